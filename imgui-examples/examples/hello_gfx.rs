@@ -5,6 +5,7 @@ extern crate glutin;
 extern crate imgui;
 extern crate imgui_gfx_renderer;
 extern crate imgui_sys;
+extern crate imgui_winit_support;
 
 use imgui::*;
 
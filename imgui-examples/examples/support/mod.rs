@@ -1,18 +1,9 @@
-use imgui::{FontGlyphRange, ImFontConfig, ImGui, ImGuiMouseCursor, Ui};
+use imgui::{FontGlyphRange, ImFontConfig, ImGui, Ui};
+use imgui_winit_support::WinitPlatform;
 use std::time::Instant;
 
-#[derive(Copy, Clone, PartialEq, Debug, Default)]
-struct MouseState {
-    pos: (i32, i32),
-    pressed: (bool, bool, bool),
-    wheel: f32,
-}
-
 pub fn run<F: FnMut(&Ui) -> bool>(title: String, clear_color: [f32; 4], mut run_ui: F) {
-    use glium::glutin::{
-        self,
-        dpi::{LogicalPosition, LogicalSize},
-    };
+    use glium::glutin::{self, dpi::LogicalSize};
     use glium::{Display, Surface};
     use imgui_glium_renderer::Renderer;
 
@@ -25,6 +16,7 @@ pub fn run<F: FnMut(&Ui) -> bool>(title: String, clear_color: [f32; 4], mut run_
 
     let mut imgui = ImGui::init();
     imgui.set_ini_filename(None);
+    imgui.set_hidpi_font_scaling(true);
     let config = ImFontConfig::new()
         .oversample_h(1)
         .pixel_snap_h(true)
@@ -37,75 +29,21 @@ pub fn run<F: FnMut(&Ui) -> bool>(title: String, clear_color: [f32; 4], mut run_
     config.merge_mode(true).add_default_font(&mut imgui.fonts());
     let mut renderer = Renderer::init(&mut imgui, &display).expect("Failed to initialize renderer");
 
-    configure_keys(&mut imgui);
+    let mut platform = WinitPlatform::new();
+    platform.attach_window(&display.gl_window());
 
     let mut last_frame = Instant::now();
-    let mut mouse_state = MouseState::default();
     let mut quit = false;
 
     loop {
         events_loop.poll_events(|event| {
-            use glium::glutin::ElementState::Pressed;
-            use glium::glutin::WindowEvent::*;
-            use glium::glutin::{Event, MouseButton, MouseScrollDelta, TouchPhase};
+            use glium::glutin::{Event, WindowEvent::CloseRequested};
+
+            platform.handle_event(&mut imgui, &event);
 
             if let Event::WindowEvent { event, .. } = event {
                 match event {
                     CloseRequested => quit = true,
-                    KeyboardInput { input, .. } => {
-                        use glium::glutin::VirtualKeyCode as Key;
-
-                        let pressed = input.state == Pressed;
-                        match input.virtual_keycode {
-                            Some(Key::Tab) => imgui.set_key(0, pressed),
-                            Some(Key::Left) => imgui.set_key(1, pressed),
-                            Some(Key::Right) => imgui.set_key(2, pressed),
-                            Some(Key::Up) => imgui.set_key(3, pressed),
-                            Some(Key::Down) => imgui.set_key(4, pressed),
-                            Some(Key::PageUp) => imgui.set_key(5, pressed),
-                            Some(Key::PageDown) => imgui.set_key(6, pressed),
-                            Some(Key::Home) => imgui.set_key(7, pressed),
-                            Some(Key::End) => imgui.set_key(8, pressed),
-                            Some(Key::Delete) => imgui.set_key(9, pressed),
-                            Some(Key::Back) => imgui.set_key(10, pressed),
-                            Some(Key::Return) => imgui.set_key(11, pressed),
-                            Some(Key::Escape) => imgui.set_key(12, pressed),
-                            Some(Key::A) => imgui.set_key(13, pressed),
-                            Some(Key::C) => imgui.set_key(14, pressed),
-                            Some(Key::V) => imgui.set_key(15, pressed),
-                            Some(Key::X) => imgui.set_key(16, pressed),
-                            Some(Key::Y) => imgui.set_key(17, pressed),
-                            Some(Key::Z) => imgui.set_key(18, pressed),
-                            Some(Key::LControl) | Some(Key::RControl) => {
-                                imgui.set_key_ctrl(pressed)
-                            }
-                            Some(Key::LShift) | Some(Key::RShift) => imgui.set_key_shift(pressed),
-                            Some(Key::LAlt) | Some(Key::RAlt) => imgui.set_key_alt(pressed),
-                            Some(Key::LWin) | Some(Key::RWin) => imgui.set_key_super(pressed),
-                            _ => {}
-                        }
-                    }
-                    CursorMoved {
-                        position: LogicalPosition { x, y },
-                        ..
-                    } => mouse_state.pos = (x as i32, y as i32),
-                    MouseInput { state, button, .. } => match button {
-                        MouseButton::Left => mouse_state.pressed.0 = state == Pressed,
-                        MouseButton::Right => mouse_state.pressed.1 = state == Pressed,
-                        MouseButton::Middle => mouse_state.pressed.2 = state == Pressed,
-                        _ => {}
-                    },
-                    MouseWheel {
-                        delta: MouseScrollDelta::LineDelta(_, y),
-                        phase: TouchPhase::Moved,
-                        ..
-                    } => mouse_state.wheel = y,
-                    MouseWheel {
-                        delta: MouseScrollDelta::PixelDelta(LogicalPosition { y, .. }),
-                        phase: TouchPhase::Moved,
-                        ..
-                    } => mouse_state.wheel = y as f32,
-                    ReceivedCharacter(c) => imgui.add_input_character(c),
                     _ => (),
                 }
             }
@@ -116,33 +54,17 @@ pub fn run<F: FnMut(&Ui) -> bool>(title: String, clear_color: [f32; 4], mut run_
         let delta_s = delta.as_secs() as f32 + delta.subsec_nanos() as f32 / 1_000_000_000.0;
         last_frame = now;
 
-        update_mouse(&mut imgui, &mut mouse_state);
-
         let gl_window = display.gl_window();
+        platform.update_cursor(&imgui, &gl_window);
 
-        let mouse_cursor = imgui.mouse_cursor();
-        if imgui.mouse_draw_cursor() || mouse_cursor == ImGuiMouseCursor::None {
-            // Hide OS cursor
-            gl_window.hide_cursor(true);
-        } else {
-            // Set OS cursor
-            gl_window.hide_cursor(false);
-            gl_window.set_cursor(match mouse_cursor {
-                ImGuiMouseCursor::None => unreachable!("mouse_cursor was None!"),
-                ImGuiMouseCursor::Arrow => glutin::MouseCursor::Arrow,
-                ImGuiMouseCursor::TextInput => glutin::MouseCursor::Text,
-                ImGuiMouseCursor::ResizeAll => glutin::MouseCursor::Move,
-                ImGuiMouseCursor::ResizeNS => glutin::MouseCursor::NsResize,
-                ImGuiMouseCursor::ResizeEW => glutin::MouseCursor::EwResize,
-                ImGuiMouseCursor::ResizeNESW => glutin::MouseCursor::NeswResize,
-                ImGuiMouseCursor::ResizeNWSE => glutin::MouseCursor::NwseResize,
-                x @ _ => unreachable!("only count enum variants here :{:?}", x),
-            });
+        if imgui.update_hidpi_scale(platform.hidpi_factor() as f32) {
+            renderer
+                .reload_font_texture(&mut imgui)
+                .expect("Failed to reload font texture");
         }
 
-        let size_points = gl_window.get_inner_size().unwrap();
-        let size_pixels = size_points.to_physical(gl_window.get_hidpi_factor());
-        let ui = imgui.frame(size_points.into(), size_pixels.into(), delta_s);
+        let (size_points, size_pixels) = platform.frame_size(&gl_window).unwrap();
+        let ui = imgui.frame(size_points, size_pixels, delta_s);
         if !run_ui(&ui) {
             break;
         }
@@ -162,41 +84,3 @@ pub fn run<F: FnMut(&Ui) -> bool>(title: String, clear_color: [f32; 4], mut run_
         }
     }
 }
-
-fn configure_keys(imgui: &mut ImGui) {
-    use imgui::ImGuiKey;
-
-    imgui.set_imgui_key(ImGuiKey::Tab, 0);
-    imgui.set_imgui_key(ImGuiKey::LeftArrow, 1);
-    imgui.set_imgui_key(ImGuiKey::RightArrow, 2);
-    imgui.set_imgui_key(ImGuiKey::UpArrow, 3);
-    imgui.set_imgui_key(ImGuiKey::DownArrow, 4);
-    imgui.set_imgui_key(ImGuiKey::PageUp, 5);
-    imgui.set_imgui_key(ImGuiKey::PageDown, 6);
-    imgui.set_imgui_key(ImGuiKey::Home, 7);
-    imgui.set_imgui_key(ImGuiKey::End, 8);
-    imgui.set_imgui_key(ImGuiKey::Delete, 9);
-    imgui.set_imgui_key(ImGuiKey::Backspace, 10);
-    imgui.set_imgui_key(ImGuiKey::Enter, 11);
-    imgui.set_imgui_key(ImGuiKey::Escape, 12);
-    imgui.set_imgui_key(ImGuiKey::A, 13);
-    imgui.set_imgui_key(ImGuiKey::C, 14);
-    imgui.set_imgui_key(ImGuiKey::V, 15);
-    imgui.set_imgui_key(ImGuiKey::X, 16);
-    imgui.set_imgui_key(ImGuiKey::Y, 17);
-    imgui.set_imgui_key(ImGuiKey::Z, 18);
-}
-
-fn update_mouse(imgui: &mut ImGui, mouse_state: &mut MouseState) {
-    // Both mouse position, and framebuffer dimensions are in logical scales, no rescaling necessary
-    imgui.set_mouse_pos(mouse_state.pos.0 as f32, mouse_state.pos.1 as f32);
-    imgui.set_mouse_down(&[
-        mouse_state.pressed.0,
-        mouse_state.pressed.1,
-        mouse_state.pressed.2,
-        false,
-        false,
-    ]);
-    imgui.set_mouse_wheel(mouse_state.wheel);
-    mouse_state.wheel = 0.0;
-}
@@ -2,12 +2,12 @@
 extern crate gfx;
 extern crate imgui;
 
-use gfx::handle::{Buffer, RenderTargetView};
-use gfx::memory::Bind;
+use gfx::handle::{Buffer, RenderTargetView, ShaderResourceView};
+use gfx::memory::{Bind, Typed};
 use gfx::texture::{FilterMethod, SamplerInfo, WrapMode};
 use gfx::traits::FactoryExt;
 use gfx::{Bundle, CommandBuffer, Encoder, Factory, IntoIndexBuffer, Rect, Resources, Slice};
-use imgui::{DrawList, ImDrawIdx, ImDrawVert, ImGui, Ui};
+use imgui::{DrawList, ImDrawIdx, ImDrawVert, ImGui, TextureFormat, Ui};
 
 pub type RendererResult<T> = Result<T, RendererError>;
 
@@ -59,8 +59,32 @@ pub enum Shaders {
 }
 
 impl Shaders {
-    fn get_program_code(self) -> (&'static [u8], &'static [u8]) {
+    fn get_program_code(self, format: TextureFormat) -> (&'static [u8], &'static [u8]) {
         use Shaders::*;
+        if format == TextureFormat::Alpha8 {
+            return match self {
+                GlSl400 => (
+                    include_bytes!("shader/glsl_400.vert"),
+                    include_bytes!("shader/glsl_400_alpha8.frag"),
+                ),
+                GlSl130 => (
+                    include_bytes!("shader/glsl_130.vert"),
+                    include_bytes!("shader/glsl_130_alpha8.frag"),
+                ),
+                GlSl110 => (
+                    include_bytes!("shader/glsl_110.vert"),
+                    include_bytes!("shader/glsl_110_alpha8.frag"),
+                ),
+                GlSlEs300 => (
+                    include_bytes!("shader/glsles_300.vert"),
+                    include_bytes!("shader/glsles_300_alpha8.frag"),
+                ),
+                GlSlEs100 => (
+                    include_bytes!("shader/glsles_100.vert"),
+                    include_bytes!("shader/glsles_100_alpha8.frag"),
+                ),
+            };
+        }
         match self {
             GlSl400 => (
                 include_bytes!("shader/glsl_400.vert"),
@@ -89,6 +113,38 @@ impl Shaders {
 pub struct Renderer<R: Resources> {
     bundle: Bundle<R, pipe::Data<R>>,
     index_buffer: Buffer<R, u16>,
+    texture_format: TextureFormat,
+}
+
+fn create_font_texture<R: Resources, F: Factory<R>>(
+    imgui: &mut ImGui,
+    factory: &mut F,
+    format: TextureFormat,
+) -> RendererResult<ShaderResourceView<R, [f32; 4]>> {
+    let texture = imgui.prepare_texture_with_format(format, |handle| {
+        let kind = gfx::texture::Kind::D2(
+            handle.width as u16,
+            handle.height as u16,
+            gfx::texture::AaMode::Single,
+        );
+        match handle.format {
+            TextureFormat::Rgba32 => factory
+                .create_texture_immutable_u8::<gfx::format::Rgba8>(
+                    kind,
+                    gfx::texture::Mipmap::Provided,
+                    &[handle.pixels],
+                ).map(|(_, view)| view),
+            // The single channel view is sampled as (r, 0, 0, 1) and expanded by the
+            // alpha8 fragment shader, so it can share the pipeline's RGBA view type.
+            TextureFormat::Alpha8 => factory
+                .create_texture_immutable_u8::<(gfx::format::R8, gfx::format::Unorm)>(
+                    kind,
+                    gfx::texture::Mipmap::Provided,
+                    &[handle.pixels],
+                ).map(|(_, view)| Typed::new(view.raw().clone())),
+        }
+    })?;
+    Ok(texture)
 }
 
 impl<R: Resources> Renderer<R> {
@@ -98,7 +154,20 @@ impl<R: Resources> Renderer<R> {
         shaders: Shaders,
         out: RenderTargetView<R, gfx::format::Rgba8>,
     ) -> RendererResult<Renderer<R>> {
-        let (vs_code, ps_code) = shaders.get_program_code();
+        Renderer::init_with_format(imgui, factory, shaders, out, TextureFormat::Rgba32)
+    }
+    /// Initializes the renderer, uploading the font atlas texture in the given format.
+    ///
+    /// `TextureFormat::Alpha8` uploads a single channel texture, which uses a quarter of the
+    /// GPU and CPU memory of the default RGBA32 atlas.
+    pub fn init_with_format<F: Factory<R>>(
+        imgui: &mut ImGui,
+        factory: &mut F,
+        shaders: Shaders,
+        out: RenderTargetView<R, gfx::format::Rgba8>,
+        format: TextureFormat,
+    ) -> RendererResult<Renderer<R>> {
+        let (vs_code, ps_code) = shaders.get_program_code(format);
         let pso = factory.create_pipeline_simple(vs_code, ps_code, pipe::new())?;
         let vertex_buffer = factory.create_buffer::<ImDrawVert>(
             256,
@@ -112,17 +181,7 @@ impl<R: Resources> Renderer<R> {
             gfx::memory::Usage::Dynamic,
             Bind::empty(),
         )?;
-        let (_, texture) = imgui.prepare_texture(|handle| {
-            factory.create_texture_immutable_u8::<gfx::format::Rgba8>(
-                gfx::texture::Kind::D2(
-                    handle.width as u16,
-                    handle.height as u16,
-                    gfx::texture::AaMode::Single,
-                ),
-                gfx::texture::Mipmap::Provided,
-                &[handle.pixels],
-            )
-        })?;
+        let texture = create_font_texture(imgui, factory, format)?;
         // TODO: set texture id in imgui
         let sampler =
             factory.create_sampler(SamplerInfo::new(FilterMethod::Scale, WrapMode::Clamp));
@@ -153,8 +212,18 @@ impl<R: Resources> Renderer<R> {
         Ok(Renderer {
             bundle: Bundle::new(slice, pso, data),
             index_buffer: index_buffer,
+            texture_format: format,
         })
     }
+    /// Uploads the font atlas texture again, e.g. after `ImGui::update_hidpi_scale` rebuilt it.
+    pub fn reload_font_texture<F: Factory<R>>(
+        &mut self,
+        imgui: &mut ImGui,
+        factory: &mut F,
+    ) -> RendererResult<()> {
+        self.bundle.data.tex.0 = create_font_texture(imgui, factory, self.texture_format)?;
+        Ok(())
+    }
     pub fn update_render_target(&mut self, out: RenderTargetView<R, gfx::format::Rgba8>) {
         self.bundle.data.out = out;
     }
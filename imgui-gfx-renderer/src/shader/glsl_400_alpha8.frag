@@ -0,0 +1,12 @@
+#version 400
+
+uniform sampler2D tex;
+
+in vec2 f_uv;
+in vec4 f_color;
+
+out vec4 Target0;
+
+void main() {
+  Target0 = f_color * vec4(1.0, 1.0, 1.0, texture(tex, f_uv.st).r);
+}
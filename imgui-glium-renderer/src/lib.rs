@@ -8,7 +8,7 @@ use glium::program;
 use glium::texture;
 use glium::vertex;
 use glium::{DrawError, GlObject, IndexBuffer, Program, Surface, Texture2d, VertexBuffer};
-use imgui::{DrawList, ImDrawIdx, ImDrawVert, ImGui, Ui};
+use imgui::{DrawList, ImDrawIdx, ImDrawVert, ImGui, TextureFormat, Ui};
 use std::borrow::Cow;
 use std::fmt;
 use std::rc::Rc;
@@ -64,13 +64,30 @@ pub struct Renderer {
 
 impl Renderer {
     pub fn init<F: Facade>(imgui: &mut ImGui, ctx: &F) -> RendererResult<Renderer> {
-        let device_objects = try!(DeviceObjects::init(imgui, ctx));
+        Renderer::init_with_format(imgui, ctx, TextureFormat::Rgba32)
+    }
+
+    /// Initializes the renderer, uploading the font atlas texture in the given format.
+    ///
+    /// `TextureFormat::Alpha8` uploads a single channel texture, which uses a quarter of the
+    /// GPU and CPU memory of the default RGBA32 atlas.
+    pub fn init_with_format<F: Facade>(
+        imgui: &mut ImGui,
+        ctx: &F,
+        format: TextureFormat,
+    ) -> RendererResult<Renderer> {
+        let device_objects = try!(DeviceObjects::init_with_format(imgui, ctx, format));
         Ok(Renderer {
             ctx: Rc::clone(ctx.get_context()),
             device_objects: device_objects,
         })
     }
 
+    /// Uploads the font atlas texture again, e.g. after `ImGui::update_hidpi_scale` rebuilt it.
+    pub fn reload_font_texture(&mut self, imgui: &mut ImGui) -> RendererResult<()> {
+        self.device_objects.reload_font_texture(imgui, &self.ctx)
+    }
+
     pub fn render<'a, S: Surface>(&mut self, surface: &mut S, ui: Ui<'a>) -> RendererResult<()> {
         let _ = self.ctx.insert_debug_marker("imgui-rs: starting rendering");
         let result = ui.render(|ui, draw_data| {
@@ -163,6 +180,7 @@ pub struct DeviceObjects {
     index_buffer: IndexBuffer<ImDrawIdx>,
     program: Program,
     texture: Texture2d,
+    texture_format: TextureFormat,
 }
 
 fn compile_default_program<F: Facade>(
@@ -198,10 +216,86 @@ fn compile_default_program<F: Facade>(
     )
 }
 
+fn compile_alpha8_program<F: Facade>(
+    ctx: &F,
+) -> Result<Program, program::ProgramChooserCreationError> {
+    program!(
+        ctx,
+        400 => {
+            vertex: include_str!("shader/glsl_400.vert"),
+            fragment: include_str!("shader/glsl_400_alpha8.frag"),
+            outputs_srgb: true,
+        },
+        130 => {
+            vertex: include_str!("shader/glsl_130.vert"),
+            fragment: include_str!("shader/glsl_130_alpha8.frag"),
+            outputs_srgb: true,
+        },
+        110 => {
+            vertex: include_str!("shader/glsl_110.vert"),
+            fragment: include_str!("shader/glsl_110_alpha8.frag"),
+            outputs_srgb: true,
+        },
+        300 es => {
+            vertex: include_str!("shader/glsles_300.vert"),
+            fragment: include_str!("shader/glsles_300_alpha8.frag"),
+            outputs_srgb: true,
+        },
+        100 es => {
+            vertex: include_str!("shader/glsles_100.vert"),
+            fragment: include_str!("shader/glsles_100_alpha8.frag"),
+            outputs_srgb: true,
+        },
+    )
+}
+
+fn upload_font_texture<F: Facade>(
+    im_gui: &mut ImGui,
+    ctx: &F,
+    format: TextureFormat,
+) -> RendererResult<Texture2d> {
+    use glium::texture::{ClientFormat, MipmapsOption, RawImage2d, UncompressedFloatFormat};
+
+    let texture = try!(im_gui.prepare_texture_with_format(format, |handle| {
+        match handle.format {
+            TextureFormat::Rgba32 => {
+                let data = RawImage2d {
+                    data: Cow::Borrowed(handle.pixels),
+                    width: handle.width,
+                    height: handle.height,
+                    format: ClientFormat::U8U8U8U8,
+                };
+                Texture2d::new(ctx, data)
+            }
+            TextureFormat::Alpha8 => {
+                let data = RawImage2d {
+                    data: Cow::Borrowed(handle.pixels),
+                    width: handle.width,
+                    height: handle.height,
+                    format: ClientFormat::U8,
+                };
+                Texture2d::with_format(
+                    ctx,
+                    data,
+                    UncompressedFloatFormat::U8,
+                    MipmapsOption::NoMipmap,
+                )
+            }
+        }
+    }));
+    im_gui.set_texture_id(texture.get_id() as usize);
+    Ok(texture)
+}
+
 impl DeviceObjects {
     pub fn init<F: Facade>(im_gui: &mut ImGui, ctx: &F) -> RendererResult<DeviceObjects> {
-        use glium::texture::{ClientFormat, RawImage2d};
-
+        DeviceObjects::init_with_format(im_gui, ctx, TextureFormat::Rgba32)
+    }
+    pub fn init_with_format<F: Facade>(
+        im_gui: &mut ImGui,
+        ctx: &F,
+        format: TextureFormat,
+    ) -> RendererResult<DeviceObjects> {
         let vertex_buffer = try!(VertexBuffer::empty_dynamic(ctx, 0));
         let index_buffer = try!(IndexBuffer::empty_dynamic(
             ctx,
@@ -209,25 +303,28 @@ impl DeviceObjects {
             0,
         ));
 
-        let program = try!(compile_default_program(ctx));
-        let texture = try!(im_gui.prepare_texture(|handle| {
-            let data = RawImage2d {
-                data: Cow::Borrowed(handle.pixels),
-                width: handle.width,
-                height: handle.height,
-                format: ClientFormat::U8U8U8U8,
-            };
-            Texture2d::new(ctx, data)
-        }));
-        im_gui.set_texture_id(texture.get_id() as usize);
+        let program = try!(match format {
+            TextureFormat::Rgba32 => compile_default_program(ctx),
+            TextureFormat::Alpha8 => compile_alpha8_program(ctx),
+        });
+        let texture = try!(upload_font_texture(im_gui, ctx, format));
 
         Ok(DeviceObjects {
             vertex_buffer: vertex_buffer,
             index_buffer: index_buffer,
             program: program,
             texture: texture,
+            texture_format: format,
         })
     }
+    pub fn reload_font_texture<F: Facade>(
+        &mut self,
+        im_gui: &mut ImGui,
+        ctx: &F,
+    ) -> RendererResult<()> {
+        self.texture = try!(upload_font_texture(im_gui, ctx, self.texture_format));
+        Ok(())
+    }
     pub fn upload_vertex_buffer<F: Facade>(
         &mut self,
         ctx: &F,
@@ -0,0 +1,13 @@
+#version 110
+
+uniform sampler2D tex;
+
+varying vec2 f_uv;
+varying vec4 f_color;
+
+// Built-in:
+// vec4 gl_FragColor
+
+void main() {
+  gl_FragColor = f_color * vec4(1.0, 1.0, 1.0, texture2D(tex, f_uv.st).r);
+}
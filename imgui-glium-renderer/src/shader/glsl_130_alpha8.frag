@@ -0,0 +1,12 @@
+#version 130
+
+uniform sampler2D tex;
+
+in vec2 f_uv;
+in vec4 f_color;
+
+out vec4 out_color;
+
+void main() {
+  out_color = f_color * vec4(1.0, 1.0, 1.0, texture(tex, f_uv.st).r);
+}
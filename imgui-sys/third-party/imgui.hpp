@@ -1,2 +1,10 @@
 #define IMGUI_DISABLE_OBSOLETE_FUNCTIONS 1
-#include "./imgui/imgui.h"
\ No newline at end of file
+#include "./imgui/imgui.h"
+
+// Internal functions used by imgui-rs. They are declared here with the same signatures as in
+// imgui_internal.h, which is not bound as a whole.
+namespace ImGui
+{
+    IMGUI_API void PushItemFlag(int option, bool enabled);
+    IMGUI_API void PopItemFlag();
+}
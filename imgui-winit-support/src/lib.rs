@@ -0,0 +1,593 @@
+//! winit support code for the imgui crate.
+//!
+//! Translates winit window events into `imgui::Event`s, keeps track of the HiDPI factor and
+//! updates the OS mouse cursor.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # extern crate imgui;
+//! # extern crate imgui_winit_support;
+//! # extern crate winit;
+//! use imgui::ImGui;
+//! use imgui_winit_support::WinitPlatform;
+//!
+//! # fn main() {
+//! let mut events_loop = winit::EventsLoop::new();
+//! let window = winit::Window::new(&events_loop).unwrap();
+//!
+//! let mut imgui = ImGui::init();
+//! let mut platform = WinitPlatform::new();
+//! platform.attach_window(&window);
+//!
+//! loop {
+//!     events_loop.poll_events(|event| platform.handle_event(&mut imgui, &event));
+//!     platform.update_cursor(&imgui, &window);
+//!
+//!     let (size_points, size_pixels) = platform.frame_size(&window).unwrap();
+//!     let ui = imgui.frame(size_points, size_pixels, 1.0 / 60.0);
+//!     // Build and render the UI...
+//! #   break;
+//! }
+//! # }
+//! ```
+
+extern crate imgui;
+extern crate winit;
+
+use imgui::{Event as ImGuiEvent, ImGui, ImGuiMouseCursor, ImMouseButton, Key};
+use winit::dpi::{LogicalPosition, LogicalSize};
+use winit::{
+    ElementState, Event, ModifiersState, MouseButton, MouseCursor, MouseScrollDelta,
+    VirtualKeyCode, Window, WindowEvent,
+};
+
+// imgui scrolls 5 lines of text per wheel line, which is 65 points with the default font, so
+// trackpads reporting precise deltas in points scroll content by about the same distance
+const PIXELS_PER_WHEEL_LINE: f64 = 65.0;
+
+/// Feeds winit window events to imgui.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WinitPlatform {
+    hidpi_factor: f64,
+}
+
+impl WinitPlatform {
+    pub fn new() -> WinitPlatform { WinitPlatform { hidpi_factor: 1.0 } }
+    /// Reads the HiDPI factor of the window events will come from.
+    pub fn attach_window(&mut self, window: &Window) {
+        self.hidpi_factor = window.get_hidpi_factor();
+    }
+    pub fn hidpi_factor(&self) -> f64 { self.hidpi_factor }
+    /// Handles a winit event, ignoring events which are not window events.
+    ///
+    /// Cursor positions are logical, like the points imgui works in, so they are passed as is.
+    ///
+    /// The Ctrl, Shift, Alt and Super state is taken from the `modifiers` winit sends with
+    /// keyboard and mouse events, so it stays right when a modifier is pressed or released
+    /// while the window is not focused.
+    ///
+    /// # Example
+    /// ```rust
+    /// # extern crate imgui;
+    /// # extern crate imgui_winit_support;
+    /// # extern crate winit;
+    /// # use imgui::ImGui;
+    /// # use imgui_winit_support::WinitPlatform;
+    /// # use winit::dpi::LogicalPosition;
+    /// # use winit::{DeviceId, Event, ModifiersState, WindowEvent, WindowId};
+    /// # fn main() {
+    /// let mut imgui = ImGui::init();
+    /// let mut platform = WinitPlatform::new();
+    ///
+    /// let event = Event::WindowEvent {
+    ///     window_id: unsafe { WindowId::dummy() },
+    ///     event: WindowEvent::CursorMoved {
+    ///         device_id: unsafe { DeviceId::dummy() },
+    ///         position: LogicalPosition::new(10.0, 20.0),
+    ///         modifiers: ModifiersState::default(),
+    ///     },
+    /// };
+    /// platform.handle_event(&mut imgui, &event);
+    /// assert_eq!(imgui.mouse_pos(), (10.0, 20.0));
+    /// # }
+    /// ```
+    pub fn handle_event(&mut self, imgui: &mut ImGui, event: &Event) {
+        match *event {
+            Event::WindowEvent { ref event, .. } => {
+                if let WindowEvent::HiDpiFactorChanged(factor) = *event {
+                    self.hidpi_factor = factor;
+                }
+                if let Some(event) = self.translate_event(event) {
+                    imgui.handle_event(event);
+                }
+                if let Some(modifiers) = event_modifiers(event) {
+                    imgui.set_key_ctrl(modifiers.ctrl);
+                    imgui.set_key_shift(modifiers.shift);
+                    imgui.set_key_alt(modifiers.alt);
+                    imgui.set_key_super(modifiers.logo);
+                }
+            }
+            _ => (),
+        }
+    }
+    /// Translates a winit window event to an imgui event.
+    ///
+    /// Precise scroll deltas, reported in points by trackpads, are converted to wheel lines.
+    ///
+    /// # Example
+    /// ```rust
+    /// # extern crate imgui;
+    /// # extern crate imgui_winit_support;
+    /// # extern crate winit;
+    /// # use imgui::{Event, ImMouseButton};
+    /// # use imgui_winit_support::WinitPlatform;
+    /// # use winit::{DeviceId, ElementState, ModifiersState, MouseButton, WindowEvent};
+    /// # fn main() {
+    /// let platform = WinitPlatform::new();
+    /// let event = WindowEvent::MouseInput {
+    ///     device_id: unsafe { DeviceId::dummy() },
+    ///     state: ElementState::Pressed,
+    ///     button: MouseButton::Other(8),
+    ///     modifiers: ModifiersState::default(),
+    /// };
+    /// assert_eq!(
+    ///     platform.translate_event(&event),
+    ///     Some(Event::MouseDown(ImMouseButton::Extra1))
+    /// );
+    /// # }
+    /// ```
+    pub fn translate_event(&self, event: &WindowEvent) -> Option<ImGuiEvent> {
+        match *event {
+            WindowEvent::Resized(size_points) => {
+                let size_pixels = size_points.to_physical(self.hidpi_factor);
+                Some(ImGuiEvent::Resize(size_points.into(), size_pixels.into()))
+            }
+            WindowEvent::KeyboardInput { input, .. } => input
+                .virtual_keycode
+                .and_then(translate_key)
+                .map(|key| match input.state {
+                    ElementState::Pressed => ImGuiEvent::KeyDown(key),
+                    ElementState::Released => ImGuiEvent::KeyUp(key),
+                }),
+            // Control characters are handled as keys
+            WindowEvent::ReceivedCharacter(ch) if !ch.is_control() => Some(ImGuiEvent::Text(ch)),
+            WindowEvent::CursorMoved {
+                position: LogicalPosition { x, y },
+                ..
+            } => Some(ImGuiEvent::MouseMove(x as f32, y as f32)),
+            WindowEvent::CursorLeft { .. } => Some(ImGuiEvent::MouseLeave),
+            WindowEvent::MouseInput { state, button, .. } => {
+                translate_mouse_button(button).map(|button| match state {
+                    ElementState::Pressed => ImGuiEvent::MouseDown(button),
+                    ElementState::Released => ImGuiEvent::MouseUp(button),
+                })
+            }
+            WindowEvent::MouseWheel { delta, .. } => Some(match delta {
+                MouseScrollDelta::LineDelta(x, y) => ImGuiEvent::MouseWheel(x, y),
+                MouseScrollDelta::PixelDelta(LogicalPosition { x, y }) => ImGuiEvent::MouseWheel(
+                    (x / PIXELS_PER_WHEEL_LINE) as f32,
+                    (y / PIXELS_PER_WHEEL_LINE) as f32,
+                ),
+            }),
+            WindowEvent::Focused(focused) => Some(ImGuiEvent::Focus(focused)),
+            _ => None,
+        }
+    }
+    /// Returns the window size in points and in pixels, as expected by `ImGui::frame`.
+    pub fn frame_size(&self, window: &Window) -> Option<((u32, u32), (u32, u32))> {
+        window.get_inner_size().map(|size_points: LogicalSize| {
+            let size_pixels = size_points.to_physical(self.hidpi_factor);
+            (size_points.into(), size_pixels.into())
+        })
+    }
+    /// Shows, hides or changes the OS cursor to match the cursor imgui requests, and moves it
+    /// when navigation wants to set the mouse position.
+    pub fn update_cursor(&self, imgui: &ImGui, window: &Window) {
+        if imgui.want_set_mouse_pos() {
+            let (x, y) = imgui.mouse_pos();
+            let _ = window.set_cursor_position(LogicalPosition::new(x as f64, y as f64));
+        }
+        let mouse_cursor = imgui.mouse_cursor();
+        match translate_cursor(mouse_cursor) {
+            Some(cursor) if !imgui.mouse_draw_cursor() => {
+                window.hide_cursor(false);
+                window.set_cursor(cursor);
+            }
+            _ => window.hide_cursor(true),
+        }
+    }
+}
+
+fn event_modifiers(event: &WindowEvent) -> Option<ModifiersState> {
+    match *event {
+        WindowEvent::KeyboardInput { input, .. } => match input.virtual_keycode {
+            // Platforms disagree on whether the event of a modifier key includes that key, so
+            // its own key state is used instead
+            Some(VirtualKeyCode::LControl)
+            | Some(VirtualKeyCode::RControl)
+            | Some(VirtualKeyCode::LShift)
+            | Some(VirtualKeyCode::RShift)
+            | Some(VirtualKeyCode::LAlt)
+            | Some(VirtualKeyCode::RAlt)
+            | Some(VirtualKeyCode::LWin)
+            | Some(VirtualKeyCode::RWin) => None,
+            _ => Some(input.modifiers),
+        },
+        WindowEvent::CursorMoved { modifiers, .. }
+        | WindowEvent::MouseInput { modifiers, .. }
+        | WindowEvent::MouseWheel { modifiers, .. } => Some(modifiers),
+        _ => None,
+    }
+}
+
+impl Default for WinitPlatform {
+    fn default() -> Self { WinitPlatform::new() }
+}
+
+/// Translates a winit virtual key code to an imgui key.
+///
+/// # Example
+/// ```rust
+/// # extern crate imgui;
+/// # extern crate imgui_winit_support;
+/// # extern crate winit;
+/// # use imgui::Key;
+/// # use imgui_winit_support::translate_key;
+/// # use winit::VirtualKeyCode;
+/// # fn main() {
+/// assert_eq!(translate_key(VirtualKeyCode::F5), Some(Key::F5));
+/// assert_eq!(translate_key(VirtualKeyCode::Numpad7), Some(Key::Keypad7));
+/// assert_eq!(translate_key(VirtualKeyCode::Mail), None);
+/// # }
+/// ```
+pub fn translate_key(key: VirtualKeyCode) -> Option<Key> {
+    Some(match key {
+        VirtualKeyCode::Tab => Key::Tab,
+        VirtualKeyCode::Left => Key::LeftArrow,
+        VirtualKeyCode::Right => Key::RightArrow,
+        VirtualKeyCode::Up => Key::UpArrow,
+        VirtualKeyCode::Down => Key::DownArrow,
+        VirtualKeyCode::PageUp => Key::PageUp,
+        VirtualKeyCode::PageDown => Key::PageDown,
+        VirtualKeyCode::Home => Key::Home,
+        VirtualKeyCode::End => Key::End,
+        VirtualKeyCode::Insert => Key::Insert,
+        VirtualKeyCode::Delete => Key::Delete,
+        VirtualKeyCode::Back => Key::Backspace,
+        VirtualKeyCode::Space => Key::Space,
+        VirtualKeyCode::Return => Key::Enter,
+        VirtualKeyCode::Escape => Key::Escape,
+        VirtualKeyCode::A => Key::A,
+        VirtualKeyCode::B => Key::B,
+        VirtualKeyCode::C => Key::C,
+        VirtualKeyCode::D => Key::D,
+        VirtualKeyCode::E => Key::E,
+        VirtualKeyCode::F => Key::F,
+        VirtualKeyCode::G => Key::G,
+        VirtualKeyCode::H => Key::H,
+        VirtualKeyCode::I => Key::I,
+        VirtualKeyCode::J => Key::J,
+        VirtualKeyCode::K => Key::K,
+        VirtualKeyCode::L => Key::L,
+        VirtualKeyCode::M => Key::M,
+        VirtualKeyCode::N => Key::N,
+        VirtualKeyCode::O => Key::O,
+        VirtualKeyCode::P => Key::P,
+        VirtualKeyCode::Q => Key::Q,
+        VirtualKeyCode::R => Key::R,
+        VirtualKeyCode::S => Key::S,
+        VirtualKeyCode::T => Key::T,
+        VirtualKeyCode::U => Key::U,
+        VirtualKeyCode::V => Key::V,
+        VirtualKeyCode::W => Key::W,
+        VirtualKeyCode::X => Key::X,
+        VirtualKeyCode::Y => Key::Y,
+        VirtualKeyCode::Z => Key::Z,
+        VirtualKeyCode::Key0 => Key::Key0,
+        VirtualKeyCode::Key1 => Key::Key1,
+        VirtualKeyCode::Key2 => Key::Key2,
+        VirtualKeyCode::Key3 => Key::Key3,
+        VirtualKeyCode::Key4 => Key::Key4,
+        VirtualKeyCode::Key5 => Key::Key5,
+        VirtualKeyCode::Key6 => Key::Key6,
+        VirtualKeyCode::Key7 => Key::Key7,
+        VirtualKeyCode::Key8 => Key::Key8,
+        VirtualKeyCode::Key9 => Key::Key9,
+        VirtualKeyCode::F1 => Key::F1,
+        VirtualKeyCode::F2 => Key::F2,
+        VirtualKeyCode::F3 => Key::F3,
+        VirtualKeyCode::F4 => Key::F4,
+        VirtualKeyCode::F5 => Key::F5,
+        VirtualKeyCode::F6 => Key::F6,
+        VirtualKeyCode::F7 => Key::F7,
+        VirtualKeyCode::F8 => Key::F8,
+        VirtualKeyCode::F9 => Key::F9,
+        VirtualKeyCode::F10 => Key::F10,
+        VirtualKeyCode::F11 => Key::F11,
+        VirtualKeyCode::F12 => Key::F12,
+        VirtualKeyCode::Apostrophe => Key::Apostrophe,
+        VirtualKeyCode::Comma => Key::Comma,
+        VirtualKeyCode::Minus => Key::Minus,
+        VirtualKeyCode::Period => Key::Period,
+        VirtualKeyCode::Slash => Key::Slash,
+        VirtualKeyCode::Semicolon => Key::Semicolon,
+        VirtualKeyCode::Equals => Key::Equal,
+        VirtualKeyCode::LBracket => Key::LeftBracket,
+        VirtualKeyCode::Backslash => Key::Backslash,
+        VirtualKeyCode::RBracket => Key::RightBracket,
+        VirtualKeyCode::Grave => Key::GraveAccent,
+        VirtualKeyCode::Capital => Key::CapsLock,
+        VirtualKeyCode::Scroll => Key::ScrollLock,
+        VirtualKeyCode::Numlock => Key::NumLock,
+        VirtualKeyCode::Snapshot => Key::PrintScreen,
+        VirtualKeyCode::Pause => Key::Pause,
+        VirtualKeyCode::Numpad0 => Key::Keypad0,
+        VirtualKeyCode::Numpad1 => Key::Keypad1,
+        VirtualKeyCode::Numpad2 => Key::Keypad2,
+        VirtualKeyCode::Numpad3 => Key::Keypad3,
+        VirtualKeyCode::Numpad4 => Key::Keypad4,
+        VirtualKeyCode::Numpad5 => Key::Keypad5,
+        VirtualKeyCode::Numpad6 => Key::Keypad6,
+        VirtualKeyCode::Numpad7 => Key::Keypad7,
+        VirtualKeyCode::Numpad8 => Key::Keypad8,
+        VirtualKeyCode::Numpad9 => Key::Keypad9,
+        VirtualKeyCode::Decimal => Key::KeypadDecimal,
+        VirtualKeyCode::Divide => Key::KeypadDivide,
+        VirtualKeyCode::Multiply => Key::KeypadMultiply,
+        VirtualKeyCode::Subtract => Key::KeypadSubtract,
+        VirtualKeyCode::Add => Key::KeypadAdd,
+        VirtualKeyCode::NumpadEnter => Key::KeypadEnter,
+        VirtualKeyCode::NumpadEquals => Key::KeypadEqual,
+        VirtualKeyCode::LControl => Key::LeftCtrl,
+        VirtualKeyCode::RControl => Key::RightCtrl,
+        VirtualKeyCode::LShift => Key::LeftShift,
+        VirtualKeyCode::RShift => Key::RightShift,
+        VirtualKeyCode::LAlt => Key::LeftAlt,
+        VirtualKeyCode::RAlt => Key::RightAlt,
+        VirtualKeyCode::LWin => Key::LeftSuper,
+        VirtualKeyCode::RWin => Key::RightSuper,
+        VirtualKeyCode::Apps => Key::Menu,
+        _ => return None,
+    })
+}
+
+/// Translates an imgui cursor to the matching winit cursor, or `None` if it must be hidden.
+///
+/// # Example
+/// ```rust
+/// # extern crate imgui;
+/// # extern crate imgui_winit_support;
+/// # extern crate winit;
+/// # use imgui::ImGuiMouseCursor;
+/// # use imgui_winit_support::translate_cursor;
+/// # fn main() {
+/// assert_eq!(
+///     translate_cursor(ImGuiMouseCursor::TextInput),
+///     Some(winit::MouseCursor::Text)
+/// );
+/// assert_eq!(translate_cursor(ImGuiMouseCursor::None), None);
+/// # }
+/// ```
+pub fn translate_cursor(cursor: ImGuiMouseCursor) -> Option<MouseCursor> {
+    match cursor {
+        ImGuiMouseCursor::Arrow => Some(MouseCursor::Arrow),
+        ImGuiMouseCursor::TextInput => Some(MouseCursor::Text),
+        ImGuiMouseCursor::ResizeAll => Some(MouseCursor::Move),
+        ImGuiMouseCursor::ResizeNS => Some(MouseCursor::NsResize),
+        ImGuiMouseCursor::ResizeEW => Some(MouseCursor::EwResize),
+        ImGuiMouseCursor::ResizeNESW => Some(MouseCursor::NeswResize),
+        ImGuiMouseCursor::ResizeNWSE => Some(MouseCursor::NwseResize),
+        ImGuiMouseCursor::Hand => Some(MouseCursor::Hand),
+        _ => None,
+    }
+}
+
+/// Translates a winit mouse button to an imgui mouse button.
+///
+/// The back and forward buttons are reported by winit as `Other(8)` and `Other(9)`, and map
+/// to `Extra1` and `Extra2`.
+///
+/// # Example
+/// ```rust
+/// # extern crate imgui;
+/// # extern crate imgui_winit_support;
+/// # extern crate winit;
+/// # use imgui::ImMouseButton;
+/// # use imgui_winit_support::translate_mouse_button;
+/// # use winit::MouseButton;
+/// # fn main() {
+/// assert_eq!(translate_mouse_button(MouseButton::Right), Some(ImMouseButton::Right));
+/// assert_eq!(translate_mouse_button(MouseButton::Other(9)), Some(ImMouseButton::Extra2));
+/// assert_eq!(translate_mouse_button(MouseButton::Other(20)), None);
+/// # }
+/// ```
+pub fn translate_mouse_button(button: MouseButton) -> Option<ImMouseButton> {
+    match button {
+        MouseButton::Left => Some(ImMouseButton::Left),
+        MouseButton::Right => Some(ImMouseButton::Right),
+        MouseButton::Middle => Some(ImMouseButton::Middle),
+        MouseButton::Other(8) => Some(ImMouseButton::Extra1),
+        MouseButton::Other(9) => Some(ImMouseButton::Extra2),
+        MouseButton::Other(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::{DeviceId, KeyboardInput, TouchPhase, WindowId};
+
+    fn window_event(event: WindowEvent) -> Event {
+        Event::WindowEvent {
+            window_id: unsafe { WindowId::dummy() },
+            event,
+        }
+    }
+
+    fn keyboard_input(
+        key: VirtualKeyCode,
+        state: ElementState,
+        modifiers: ModifiersState,
+    ) -> Event {
+        window_event(WindowEvent::KeyboardInput {
+            device_id: unsafe { DeviceId::dummy() },
+            input: KeyboardInput {
+                scancode: 0,
+                state,
+                virtual_keycode: Some(key),
+                modifiers,
+            },
+        })
+    }
+
+    fn mouse_input(button: MouseButton, state: ElementState) -> Event {
+        window_event(WindowEvent::MouseInput {
+            device_id: unsafe { DeviceId::dummy() },
+            state,
+            button,
+            modifiers: ModifiersState::default(),
+        })
+    }
+
+    fn mouse_wheel(delta: MouseScrollDelta, modifiers: ModifiersState) -> Event {
+        window_event(WindowEvent::MouseWheel {
+            device_id: unsafe { DeviceId::dummy() },
+            delta,
+            phase: TouchPhase::Moved,
+            modifiers,
+        })
+    }
+
+    #[test]
+    fn test_translate_key() {
+        assert_eq!(translate_key(VirtualKeyCode::Tab), Some(Key::Tab));
+        assert_eq!(translate_key(VirtualKeyCode::Back), Some(Key::Backspace));
+        assert_eq!(translate_key(VirtualKeyCode::Return), Some(Key::Enter));
+        assert_eq!(translate_key(VirtualKeyCode::Key0), Some(Key::Key0));
+        assert_eq!(
+            translate_key(VirtualKeyCode::NumpadEnter),
+            Some(Key::KeypadEnter)
+        );
+        assert_eq!(
+            translate_key(VirtualKeyCode::RControl),
+            Some(Key::RightCtrl)
+        );
+        assert_eq!(translate_key(VirtualKeyCode::Apps), Some(Key::Menu));
+        assert_eq!(translate_key(VirtualKeyCode::Mail), None);
+    }
+
+    #[test]
+    fn test_translate_mouse_button() {
+        assert_eq!(
+            translate_mouse_button(MouseButton::Left),
+            Some(ImMouseButton::Left)
+        );
+        assert_eq!(
+            translate_mouse_button(MouseButton::Middle),
+            Some(ImMouseButton::Middle)
+        );
+        assert_eq!(
+            translate_mouse_button(MouseButton::Other(8)),
+            Some(ImMouseButton::Extra1)
+        );
+        assert_eq!(translate_mouse_button(MouseButton::Other(3)), None);
+    }
+
+    #[test]
+    fn test_translate_event() {
+        let platform = WinitPlatform::new();
+        let event = WindowEvent::ReceivedCharacter('\u{8}');
+        assert_eq!(platform.translate_event(&event), None);
+        let event = WindowEvent::ReceivedCharacter('é');
+        assert_eq!(
+            platform.translate_event(&event),
+            Some(ImGuiEvent::Text('é'))
+        );
+        let event = WindowEvent::CursorLeft {
+            device_id: unsafe { DeviceId::dummy() },
+        };
+        assert_eq!(
+            platform.translate_event(&event),
+            Some(ImGuiEvent::MouseLeave)
+        );
+        let event = WindowEvent::MouseWheel {
+            device_id: unsafe { DeviceId::dummy() },
+            delta: MouseScrollDelta::PixelDelta(LogicalPosition::new(-13.0, 130.0)),
+            phase: TouchPhase::Moved,
+            modifiers: ModifiersState::default(),
+        };
+        assert_eq!(
+            platform.translate_event(&event),
+            Some(ImGuiEvent::MouseWheel(-0.2, 2.0))
+        );
+    }
+
+    // imgui has a single global context, so everything using it runs in one test
+    #[test]
+    fn test_handle_event() {
+        let mut imgui = ImGui::init();
+        let mut platform = WinitPlatform::new();
+        let no_modifiers = ModifiersState::default();
+
+        platform.handle_event(
+            &mut imgui,
+            &mouse_input(MouseButton::Left, ElementState::Pressed),
+        );
+        platform.handle_event(
+            &mut imgui,
+            &mouse_input(MouseButton::Other(9), ElementState::Pressed),
+        );
+        assert!(imgui.is_mouse_down(ImMouseButton::Left));
+        assert!(imgui.is_mouse_down(ImMouseButton::Extra2));
+        platform.handle_event(
+            &mut imgui,
+            &mouse_input(MouseButton::Left, ElementState::Released),
+        );
+        assert!(!imgui.is_mouse_down(ImMouseButton::Left));
+
+        let delta = MouseScrollDelta::LineDelta(1.0, -2.0);
+        platform.handle_event(&mut imgui, &mouse_wheel(delta, no_modifiers));
+        platform.handle_event(&mut imgui, &mouse_wheel(delta, no_modifiers));
+        assert_eq!(imgui.mouse_wheel_h(), 2.0);
+        assert_eq!(imgui.mouse_wheel(), -4.0);
+
+        let ctrl = ModifiersState {
+            ctrl: true,
+            ..ModifiersState::default()
+        };
+        let shift_alt = ModifiersState {
+            shift: true,
+            alt: true,
+            ..ModifiersState::default()
+        };
+        platform.handle_event(
+            &mut imgui,
+            &keyboard_input(
+                VirtualKeyCode::LControl,
+                ElementState::Pressed,
+                no_modifiers,
+            ),
+        );
+        assert!(imgui.key_ctrl());
+        platform.handle_event(
+            &mut imgui,
+            &keyboard_input(VirtualKeyCode::C, ElementState::Pressed, ctrl),
+        );
+        assert!(imgui.is_key_down(Key::C.index()));
+        assert!(imgui.key_ctrl());
+        assert!(!imgui.key_shift());
+
+        // Ctrl released while the window was not focused
+        platform.handle_event(&mut imgui, &mouse_wheel(delta, shift_alt));
+        assert!(!imgui.key_ctrl());
+        assert!(imgui.key_shift());
+        assert!(imgui.key_alt());
+        assert!(!imgui.key_super());
+
+        platform.handle_event(&mut imgui, &window_event(WindowEvent::Focused(false)));
+        assert!(!imgui.key_shift());
+        assert!(!imgui.is_key_down(Key::C.index()));
+        assert!(!imgui.is_mouse_down(ImMouseButton::Extra2));
+    }
+}
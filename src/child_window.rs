@@ -0,0 +1,156 @@
+use std::marker::PhantomData;
+use sys;
+
+use super::{ImGuiWindowFlags, ImStr, ImVec2, Ui};
+
+/// The ID of a child window, either a string or an integer.
+#[derive(Copy, Clone, Debug)]
+pub enum ChildWindowId<'p> {
+    Str(&'p ImStr),
+    Int(u32),
+}
+
+impl<'p> From<&'p ImStr> for ChildWindowId<'p> {
+    fn from(id: &'p ImStr) -> Self { ChildWindowId::Str(id) }
+}
+
+impl<'p> From<u32> for ChildWindowId<'p> {
+    fn from(id: u32) -> Self { ChildWindowId::Int(id) }
+}
+
+/// A scrolling region inside the current window.
+///
+/// Unlike `ChildFrame`, it can be identified by an integer, can be ended by a token, and
+/// `build` returns whether the contents are visible so expensive contents can be skipped.
+///
+/// # Example
+/// ```rust,no_run
+/// # #[macro_use] extern crate imgui;
+/// # use imgui::*;
+/// # fn main() {
+/// # let mut imgui = ImGui::init();
+/// # let ui = imgui.frame((0, 0), (0, 0), 0.1);
+/// // Leave 100 pixels at the bottom for other widgets
+/// ui.child_window(im_str!("scrolling"))
+///     .size((0.0, -100.0))
+///     .border(true)
+///     .menu_bar(true)
+///     .build(|| {
+///         ui.menu_bar(|| {
+///             ui.menu(im_str!("Options")).build(|| {});
+///         });
+///         for i in 0..100 {
+///             ui.text(format!("Line {}", i));
+///         }
+///     });
+/// # }
+/// ```
+#[must_use]
+pub struct ChildWindow<'ui, 'p> {
+    id: ChildWindowId<'p>,
+    size: ImVec2,
+    border: bool,
+    flags: ImGuiWindowFlags,
+    _phantom: PhantomData<&'ui Ui<'ui>>,
+}
+
+impl<'ui, 'p> ChildWindow<'ui, 'p> {
+    pub fn new<I: Into<ChildWindowId<'p>>>(_: &Ui<'ui>, id: I) -> ChildWindow<'ui, 'p> {
+        ChildWindow {
+            id: id.into(),
+            size: ImVec2::zero(),
+            border: false,
+            flags: ImGuiWindowFlags::None,
+            _phantom: PhantomData,
+        }
+    }
+    /// Sets the size of the child window. A component of 0.0 uses the remaining space of the
+    /// parent window, and a negative one uses the remaining space minus its absolute value.
+    #[inline]
+    pub fn size<S: Into<ImVec2>>(mut self, size: S) -> Self {
+        self.size = size.into();
+        self
+    }
+    #[inline]
+    pub fn border(mut self, value: bool) -> Self {
+        self.border = value;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: ImGuiWindowFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn menu_bar(mut self, value: bool) -> Self {
+        self.flags.set(ImGuiWindowFlags::MenuBar, value);
+        self
+    }
+    #[inline]
+    pub fn scroll_bar(mut self, value: bool) -> Self {
+        self.flags.set(ImGuiWindowFlags::NoScrollbar, !value);
+        self
+    }
+    #[inline]
+    pub fn scrollable(mut self, value: bool) -> Self {
+        self.flags.set(ImGuiWindowFlags::NoScrollWithMouse, !value);
+        self
+    }
+    #[inline]
+    pub fn horizontal_scrollbar(mut self, value: bool) -> Self {
+        self.flags.set(ImGuiWindowFlags::HorizontalScrollbar, value);
+        self
+    }
+    #[inline]
+    pub fn always_auto_resize(mut self, value: bool) -> Self {
+        self.flags.set(ImGuiWindowFlags::AlwaysAutoResize, value);
+        self
+    }
+    #[inline]
+    pub fn always_use_window_padding(mut self, value: bool) -> Self {
+        self.flags
+            .set(ImGuiWindowFlags::AlwaysUseWindowPadding, value);
+        self
+    }
+    /// Begins the child window. It ends when the returned token is dropped.
+    pub fn begin(self) -> ChildWindowToken<'ui> {
+        let visible = unsafe {
+            match self.id {
+                ChildWindowId::Str(id) => {
+                    sys::BeginChild(id.as_ptr(), &self.size, self.border, self.flags)
+                }
+                ChildWindowId::Int(id) => sys::BeginChild1(id, &self.size, self.border, self.flags),
+            }
+        };
+        ChildWindowToken {
+            visible,
+            _phantom: PhantomData,
+        }
+    }
+    /// Builds the child window contents with `f` if they are visible, and returns whether
+    /// they were.
+    pub fn build<F: FnOnce()>(self, f: F) -> bool {
+        let token = self.begin();
+        if token.is_visible() {
+            f();
+        }
+        token.is_visible()
+    }
+}
+
+/// Ends the child window begun with `ChildWindow::begin` when dropped.
+#[must_use]
+pub struct ChildWindowToken<'ui> {
+    visible: bool,
+    _phantom: PhantomData<&'ui Ui<'ui>>,
+}
+
+impl<'ui> ChildWindowToken<'ui> {
+    /// Returns `false` if the child window is collapsed or clipped, in which case its contents
+    /// can be skipped.
+    pub fn is_visible(&self) -> bool { self.visible }
+}
+
+impl<'ui> Drop for ChildWindowToken<'ui> {
+    fn drop(&mut self) { unsafe { sys::EndChild() } }
+}
@@ -0,0 +1,79 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+use super::ImString;
+
+/// Trait for clipboard backends plugged into imgui with `ImGui::set_clipboard_backend`.
+pub trait ClipboardBackend {
+    /// Returns the current clipboard contents, if any.
+    fn get(&mut self) -> Option<String>;
+    /// Replaces the clipboard contents.
+    fn set(&mut self, value: &str);
+}
+
+/// A clipboard backend keeping its contents in memory.
+///
+/// Useful for tests and headless tools, where copy and paste must be deterministic and not
+/// depend on the platform clipboard.
+///
+/// ```rust
+/// # use imgui::*;
+/// let mut imgui = ImGui::init();
+/// imgui.set_clipboard_backend(MemoryClipboard::with_contents("pasted"));
+/// assert_eq!(imgui.clipboard_text(), Some("pasted".to_owned()));
+/// imgui.set_clipboard_text("copied");
+/// assert_eq!(imgui.clipboard_text(), Some("copied".to_owned()));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MemoryClipboard {
+    contents: Option<String>,
+}
+
+impl MemoryClipboard {
+    pub fn new() -> MemoryClipboard { MemoryClipboard::default() }
+    /// Creates a clipboard initially holding the given contents.
+    pub fn with_contents<T: Into<String>>(contents: T) -> MemoryClipboard {
+        MemoryClipboard {
+            contents: Some(contents.into()),
+        }
+    }
+    pub fn contents(&self) -> Option<&str> { self.contents.as_ref().map(|s| s.as_str()) }
+}
+
+impl ClipboardBackend for MemoryClipboard {
+    fn get(&mut self) -> Option<String> { self.contents.clone() }
+    fn set(&mut self, value: &str) { self.contents = Some(value.to_owned()); }
+}
+
+pub(crate) struct ClipboardContext {
+    backend: Box<ClipboardBackend>,
+    // imgui expects the returned string to stay valid until the next call
+    last_value: ImString,
+}
+
+impl ClipboardContext {
+    pub(crate) fn new(backend: Box<ClipboardBackend>) -> ClipboardContext {
+        ClipboardContext {
+            backend,
+            last_value: ImString::with_capacity(0),
+        }
+    }
+}
+
+pub(crate) unsafe extern "C" fn get_clipboard_text(user_data: *mut c_void) -> *const c_char {
+    let ctx = &mut *(user_data as *mut ClipboardContext);
+    match ctx.backend.get() {
+        Some(text) => {
+            ctx.last_value = ImString::new(text);
+            ctx.last_value.as_ptr()
+        }
+        None => ptr::null(),
+    }
+}
+
+pub(crate) unsafe extern "C" fn set_clipboard_text(user_data: *mut c_void, text: *const c_char) {
+    let ctx = &mut *(user_data as *mut ClipboardContext);
+    let text = CStr::from_ptr(text).to_string_lossy();
+    ctx.backend.set(&text);
+}
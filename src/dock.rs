@@ -0,0 +1,665 @@
+use std::fmt::Write;
+use std::str::Lines;
+use sys;
+
+use super::{ImGuiCol, ImGuiCond, ImGuiMouseCursor, ImMouseButton, ImStr, ImString, Ui};
+
+/// The side of a docked window another window is docked to.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DockSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum SplitDirection {
+    // Children side by side
+    Horizontal,
+    // Children stacked
+    Vertical,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum DockNode {
+    Window(ImString),
+    Split {
+        direction: SplitDirection,
+        // Fraction of the space given to the first child
+        ratio: f32,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct FloatingWindow {
+    name: ImString,
+    pos: (f32, f32),
+    size: (f32, f32),
+    // Set pos and size on the next frame, after loading or undocking from code
+    place: bool,
+}
+
+type Rect = ((f32, f32), (f32, f32));
+
+struct Splitter {
+    direction: SplitDirection,
+    rect: Rect,
+    // Space shared by both children, to turn mouse movement into a ratio
+    extent: f32,
+}
+
+enum DockAction {
+    Undock(ImString, (f32, f32), (f32, f32)),
+    Dock(ImString, Option<ImString>, DockSide),
+    Resize(usize, f32),
+}
+
+/// A docking layout tiling windows in a rectangle, IDE-style.
+///
+/// Docked windows are laid out in a tree of horizontal and vertical splits, resized with
+/// the splitters between them. Dragging a docked window by its title bar undocks it into a
+/// floating window, and dropping a floating window onto a docked one docks it to the
+/// closest side.
+///
+/// The layout can be saved with `save` and restored with `load`, typically next to the
+/// ini settings.
+///
+/// # Example
+/// ```rust,no_run
+/// # #[macro_use] extern crate imgui;
+/// # use imgui::*;
+/// # fn main() {
+/// let mut layout = DockLayout::new();
+/// layout.dock(im_str!("Scene"), None, DockSide::Left, 1.0);
+/// layout.dock(im_str!("Inspector"), Some(im_str!("Scene")), DockSide::Right, 0.25);
+/// layout.dock(im_str!("Log"), Some(im_str!("Scene")), DockSide::Bottom, 0.3);
+///
+/// # let mut imgui = ImGui::init();
+/// let ui = imgui.frame((1280, 720), (1280, 720), 0.1);
+/// layout.build(&ui, (0.0, 0.0), (1280.0, 720.0), |name| match name.to_str() {
+///     "Scene" => ui.text("Scene view"),
+///     "Inspector" => ui.text("Properties"),
+///     _ => ui.text("Messages"),
+/// });
+/// let saved = layout.save();
+/// assert_eq!(DockLayout::load(&saved).map(|l| l.save()), Some(saved));
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct DockLayout {
+    root: Option<DockNode>,
+    floating: Vec<FloatingWindow>,
+    dragging: Option<ImString>,
+    splitter_size: f32,
+}
+
+impl DockLayout {
+    pub fn new() -> DockLayout {
+        DockLayout {
+            root: None,
+            floating: Vec::new(),
+            dragging: None,
+            splitter_size: 4.0,
+        }
+    }
+    /// Sets the width of the splitters between docked windows, in pixels.
+    pub fn set_splitter_size(&mut self, size: f32) { self.splitter_size = size; }
+    /// Docks `window` next to the docked window `target`, or next to the whole layout if
+    /// `target` is `None`, taking `ratio` of the space on the given side.
+    ///
+    /// A window which is already docked or floating is moved. Returns `false` if `target` is
+    /// not docked.
+    pub fn dock(
+        &mut self,
+        window: &ImStr,
+        target: Option<&ImStr>,
+        side: DockSide,
+        ratio: f32,
+    ) -> bool {
+        if target
+            .map(|t| !self.is_docked(t) || t == window)
+            .unwrap_or(false)
+        {
+            return false;
+        }
+        self.remove(window);
+        let window = DockNode::Window(window.to_owned());
+        self.root = Some(match self.root.take() {
+            None => window,
+            Some(root) => match target {
+                Some(target) => insert(root, target, window, side, ratio),
+                None => split(root, window, side, ratio),
+            },
+        });
+        true
+    }
+    /// Undocks `window` into a floating window with the given position and size.
+    pub fn undock(&mut self, window: &ImStr, pos: (f32, f32), size: (f32, f32)) {
+        self.remove(window);
+        self.floating.push(FloatingWindow {
+            name: window.to_owned(),
+            pos,
+            size,
+            place: true,
+        });
+    }
+    /// Removes `window` from the layout, whether docked or floating.
+    pub fn remove(&mut self, window: &ImStr) {
+        self.root = self.root.take().and_then(|root| remove(root, window));
+        self.floating.retain(|f| &*f.name != window);
+    }
+    pub fn is_docked(&self, window: &ImStr) -> bool {
+        self.root
+            .as_ref()
+            .map(|root| contains(root, window))
+            .unwrap_or(false)
+    }
+    pub fn is_floating(&self, window: &ImStr) -> bool {
+        self.floating.iter().any(|f| &*f.name == window)
+    }
+    /// Builds the docked windows in the rectangle at `pos` with `size`, and the floating
+    /// windows, calling `f` with the name of each window to build its contents.
+    pub fn build<F: FnMut(&ImStr)>(
+        &mut self,
+        ui: &Ui,
+        pos: (f32, f32),
+        size: (f32, f32),
+        mut f: F,
+    ) {
+        let mut leaves = Vec::new();
+        let mut splitters = Vec::new();
+        if let Some(ref root) = self.root {
+            layout(
+                root,
+                (pos, size),
+                self.splitter_size,
+                &mut leaves,
+                &mut splitters,
+            );
+        }
+        let mut actions = Vec::new();
+
+        build_splitters(ui, pos, size, &splitters, &mut actions);
+
+        let title_height = unsafe { sys::GetFrameHeight() };
+        for &(ref name, rect) in &leaves {
+            let (leaf_pos, leaf_size) = rect;
+            ui.window(name)
+                .position(leaf_pos, ImGuiCond::Always)
+                .size(leaf_size, ImGuiCond::Always)
+                .resizable(false)
+                .collapsible(false)
+                .build(|| {
+                    f(name);
+                    // The window doesn't move while docked, so where the drag started tells
+                    // whether its title bar is being dragged
+                    let clicked = ui.mouse_clicked_pos(ImMouseButton::Left);
+                    let title_bar = (leaf_pos, (leaf_size.0, title_height));
+                    if ui.is_window_focused()
+                        && ui.is_mouse_dragging(ImMouseButton::Left)
+                        && contains_point(title_bar, clicked)
+                    {
+                        actions.push(DockAction::Undock(name.clone(), leaf_pos, leaf_size));
+                    }
+                });
+        }
+
+        let root_rect = (pos, size);
+        let mut dragging = None;
+        for floating in &mut self.floating {
+            let mut window = ui.window(&floating.name);
+            if floating.place {
+                window = window
+                    .position(floating.pos, ImGuiCond::Always)
+                    .size(floating.size, ImGuiCond::Always);
+                floating.place = false;
+            }
+            let was_dragging = self.dragging.as_ref() == Some(&floating.name);
+            let floating_name = &floating.name;
+            let mut window_pos = floating.pos;
+            let mut window_size = floating.size;
+            window.build(|| {
+                f(floating_name);
+                window_pos = ui.get_window_pos();
+                window_size = ui.get_window_size();
+                // The window follows the mouse while dragged, so the mouse stays on its title
+                let title_bar = (window_pos, (window_size.0, title_height));
+                let mouse_pos = ui.imgui().mouse_pos();
+                let on_title = contains_point(title_bar, mouse_pos);
+                if ui.is_window_focused() && ui.is_mouse_dragging(ImMouseButton::Left) && on_title {
+                    dragging = Some(floating_name.clone());
+                    let target = drop_target(&leaves, root_rect, mouse_pos);
+                    if let Some((_, target_rect, side)) = target {
+                        draw_drop_preview(ui, target_rect, side);
+                    }
+                } else if was_dragging && ui.imgui().is_mouse_released(ImMouseButton::Left) {
+                    if let Some((target, _, side)) = drop_target(&leaves, root_rect, mouse_pos) {
+                        actions.push(DockAction::Dock(floating_name.clone(), target, side));
+                    }
+                }
+            });
+            floating.pos = window_pos;
+            floating.size = window_size;
+        }
+        self.dragging = dragging;
+
+        for action in actions {
+            match action {
+                DockAction::Undock(name, pos, size) => {
+                    self.undock(&name, pos, size);
+                    // imgui keeps moving the window, which is now free to follow the mouse
+                    self.floating.last_mut().unwrap().place = false;
+                    self.dragging = Some(name);
+                }
+                DockAction::Dock(name, target, side) => {
+                    self.dock(&name, target.as_ref().map(|t| &**t), side, 0.5);
+                }
+                DockAction::Resize(index, delta) => {
+                    if let Some(ref mut root) = self.root {
+                        resize_split(root, &mut 0, index, delta);
+                    }
+                }
+            }
+        }
+    }
+    /// Serializes the layout to a string, one node per line.
+    pub fn save(&self) -> String {
+        let mut output = String::new();
+        if let Some(ref root) = self.root {
+            save_node(root, &mut output);
+        }
+        for floating in &self.floating {
+            let _ = writeln!(
+                output,
+                "floating {} {} {} {} {}",
+                floating.pos.0,
+                floating.pos.1,
+                floating.size.0,
+                floating.size.1,
+                floating.name.to_str()
+            );
+        }
+        output
+    }
+    /// Restores a layout saved with `save`, or returns `None` if `data` is malformed.
+    pub fn load(data: &str) -> Option<DockLayout> {
+        let mut layout = DockLayout::new();
+        let mut lines = data.lines();
+        let mut pending = None;
+        if let Some(line) = lines.next() {
+            if line.starts_with("floating ") {
+                pending = Some(line);
+            } else {
+                match load_node(line, &mut lines) {
+                    Some(node) => layout.root = Some(node),
+                    None => return None,
+                }
+            }
+        }
+        for line in pending.into_iter().chain(lines) {
+            match load_floating(line) {
+                Some(floating) => layout.floating.push(floating),
+                None => return None,
+            }
+        }
+        Some(layout)
+    }
+}
+
+impl Default for DockLayout {
+    fn default() -> Self { DockLayout::new() }
+}
+
+fn split(node: DockNode, window: DockNode, side: DockSide, ratio: f32) -> DockNode {
+    let direction = match side {
+        DockSide::Left | DockSide::Right => SplitDirection::Horizontal,
+        DockSide::Top | DockSide::Bottom => SplitDirection::Vertical,
+    };
+    let ratio = ratio.max(0.0).min(1.0);
+    let (first, second, ratio) = match side {
+        DockSide::Left | DockSide::Top => (window, node, ratio),
+        DockSide::Right | DockSide::Bottom => (node, window, 1.0 - ratio),
+    };
+    DockNode::Split {
+        direction,
+        ratio,
+        first: Box::new(first),
+        second: Box::new(second),
+    }
+}
+
+fn insert(
+    node: DockNode,
+    target: &ImStr,
+    window: DockNode,
+    side: DockSide,
+    ratio: f32,
+) -> DockNode {
+    match node {
+        DockNode::Window(name) => {
+            if &*name == target {
+                split(DockNode::Window(name), window, side, ratio)
+            } else {
+                DockNode::Window(name)
+            }
+        }
+        DockNode::Split {
+            direction,
+            ratio: split_ratio,
+            first,
+            second,
+        } => {
+            // The target is in one subtree only, so the window is inserted once
+            let (first, second) = if contains(&first, target) {
+                (insert(*first, target, window, side, ratio), *second)
+            } else {
+                (*first, insert(*second, target, window, side, ratio))
+            };
+            DockNode::Split {
+                direction,
+                ratio: split_ratio,
+                first: Box::new(first),
+                second: Box::new(second),
+            }
+        }
+    }
+}
+
+fn remove(node: DockNode, window: &ImStr) -> Option<DockNode> {
+    match node {
+        DockNode::Window(name) => {
+            if &*name == window {
+                None
+            } else {
+                Some(DockNode::Window(name))
+            }
+        }
+        DockNode::Split {
+            direction,
+            ratio,
+            first,
+            second,
+        } => match (remove(*first, window), remove(*second, window)) {
+            (Some(first), Some(second)) => Some(DockNode::Split {
+                direction,
+                ratio,
+                first: Box::new(first),
+                second: Box::new(second),
+            }),
+            // A split with a single child is replaced by the child
+            (first, second) => first.or(second),
+        },
+    }
+}
+
+fn contains(node: &DockNode, window: &ImStr) -> bool {
+    match *node {
+        DockNode::Window(ref name) => &**name == window,
+        DockNode::Split {
+            ref first,
+            ref second,
+            ..
+        } => contains(first, window) || contains(second, window),
+    }
+}
+
+fn contains_point(((x, y), (w, h)): Rect, (px, py): (f32, f32)) -> bool {
+    px >= x && px < x + w && py >= y && py < y + h
+}
+
+fn layout(
+    node: &DockNode,
+    rect: Rect,
+    splitter_size: f32,
+    leaves: &mut Vec<(ImString, Rect)>,
+    splitters: &mut Vec<Splitter>,
+) {
+    match *node {
+        DockNode::Window(ref name) => leaves.push((name.clone(), rect)),
+        DockNode::Split {
+            direction,
+            ratio,
+            ref first,
+            ref second,
+        } => {
+            let ((x, y), (w, h)) = rect;
+            let (first_rect, splitter_rect, second_rect, extent) = match direction {
+                SplitDirection::Horizontal => {
+                    let extent = (w - splitter_size).max(0.0);
+                    let first_w = (extent * ratio).round();
+                    (
+                        ((x, y), (first_w, h)),
+                        ((x + first_w, y), (splitter_size, h)),
+                        ((x + first_w + splitter_size, y), (extent - first_w, h)),
+                        extent,
+                    )
+                }
+                SplitDirection::Vertical => {
+                    let extent = (h - splitter_size).max(0.0);
+                    let first_h = (extent * ratio).round();
+                    (
+                        ((x, y), (w, first_h)),
+                        ((x, y + first_h), (w, splitter_size)),
+                        ((x, y + first_h + splitter_size), (w, extent - first_h)),
+                        extent,
+                    )
+                }
+            };
+            // Splitters are numbered in pre-order, matching `resize_split`
+            splitters.push(Splitter {
+                direction,
+                rect: splitter_rect,
+                extent,
+            });
+            layout(first, first_rect, splitter_size, leaves, splitters);
+            layout(second, second_rect, splitter_size, leaves, splitters);
+        }
+    }
+}
+
+fn resize_split(node: &mut DockNode, counter: &mut usize, index: usize, delta: f32) {
+    if let DockNode::Split {
+        ref mut ratio,
+        ref mut first,
+        ref mut second,
+        ..
+    } = *node
+    {
+        if *counter == index {
+            *ratio = (*ratio + delta).max(0.05).min(0.95);
+            return;
+        }
+        *counter += 1;
+        resize_split(first, counter, index, delta);
+        resize_split(second, counter, index, delta);
+    }
+}
+
+fn build_splitters(
+    ui: &Ui,
+    pos: (f32, f32),
+    size: (f32, f32),
+    splitters: &[Splitter],
+    actions: &mut Vec<DockAction>,
+) {
+    // A transparent window behind the docked windows, showing through the gaps between them
+    let host_name = unsafe { ImStr::from_utf8_with_nul_unchecked(b"##dock_host\0") };
+    ui.window(host_name)
+        .position(pos, ImGuiCond::Always)
+        .size(size, ImGuiCond::Always)
+        .title_bar(false)
+        .resizable(false)
+        .movable(false)
+        .scroll_bar(false)
+        .scrollable(false)
+        .collapsible(false)
+        .save_settings(false)
+        .no_bring_to_front_on_focus(true)
+        .bg_alpha(0.0)
+        .build(|| {
+            let splitter_id = unsafe { ImStr::from_utf8_with_nul_unchecked(b"##splitter\0") };
+            for (index, splitter) in splitters.iter().enumerate() {
+                let (min, size) = splitter.rect;
+                ui.set_cursor_screen_pos(min);
+                ui.push_id(index as i32);
+                ui.invisible_button(splitter_id, size);
+                let hovered = ui.is_item_hovered();
+                let active = ui.is_item_active();
+                ui.pop_id();
+                if hovered || active {
+                    ui.imgui().set_mouse_cursor(match splitter.direction {
+                        SplitDirection::Horizontal => ImGuiMouseCursor::ResizeEW,
+                        SplitDirection::Vertical => ImGuiMouseCursor::ResizeNS,
+                    });
+                }
+                if active && splitter.extent > 0.0 {
+                    let (dx, dy) = ui.imgui().mouse_delta();
+                    let delta = match splitter.direction {
+                        SplitDirection::Horizontal => dx,
+                        SplitDirection::Vertical => dy,
+                    };
+                    if delta != 0.0 {
+                        actions.push(DockAction::Resize(index, delta / splitter.extent));
+                    }
+                }
+                let col = if active {
+                    ImGuiCol::SeparatorActive
+                } else if hovered {
+                    ImGuiCol::SeparatorHovered
+                } else {
+                    ImGuiCol::Separator
+                };
+                let color = unsafe { sys::GetColorU32(col, 1.0) };
+                let max = (min.0 + size.0, min.1 + size.1);
+                ui.get_window_draw_list()
+                    .add_rect(min, max, color)
+                    .filled(true)
+                    .build();
+            }
+        });
+}
+
+fn drop_target(
+    leaves: &[(ImString, Rect)],
+    root_rect: Rect,
+    mouse_pos: (f32, f32),
+) -> Option<(Option<ImString>, Rect, DockSide)> {
+    let target = leaves
+        .iter()
+        .find(|&&(_, rect)| contains_point(rect, mouse_pos))
+        .map(|&(ref name, rect)| (Some(name.clone()), rect));
+    // An empty layout accepts windows anywhere in its rectangle
+    let target = match target {
+        None if leaves.is_empty() && contains_point(root_rect, mouse_pos) => {
+            Some((None, root_rect))
+        }
+        target => target,
+    };
+    target.map(|(name, rect)| {
+        let ((x, y), (w, h)) = rect;
+        let u = (mouse_pos.0 - x) / w;
+        let v = (mouse_pos.1 - y) / h;
+        let sides = [
+            (u, DockSide::Left),
+            (1.0 - u, DockSide::Right),
+            (v, DockSide::Top),
+            (1.0 - v, DockSide::Bottom),
+        ];
+        let side = sides
+            .iter()
+            .fold(
+                sides[0],
+                |closest, &side| if side.0 < closest.0 { side } else { closest },
+            )
+            .1;
+        (name, rect, side)
+    })
+}
+
+fn draw_drop_preview(ui: &Ui, ((x, y), (w, h)): Rect, side: DockSide) {
+    let (min, max) = match side {
+        DockSide::Left => ((x, y), (x + w * 0.5, y + h)),
+        DockSide::Right => ((x + w * 0.5, y), (x + w, y + h)),
+        DockSide::Top => ((x, y), (x + w, y + h * 0.5)),
+        DockSide::Bottom => ((x, y + h * 0.5), (x + w, y + h)),
+    };
+    let color = unsafe { sys::GetColorU32(ImGuiCol::DragDropTarget, 0.4) };
+    let display_size = ui.imgui().display_size();
+    let draw_list = ui.get_window_draw_list();
+    // Drawn from the dragged window, so the preview must not be clipped to it
+    draw_list.with_clip_rect((0.0, 0.0), display_size, || {
+        draw_list.add_rect(min, max, color).filled(true).build();
+    });
+}
+
+fn save_node(node: &DockNode, output: &mut String) {
+    match *node {
+        DockNode::Window(ref name) => {
+            let _ = writeln!(output, "window {}", name.to_str());
+        }
+        DockNode::Split {
+            direction,
+            ratio,
+            ref first,
+            ref second,
+        } => {
+            let direction = match direction {
+                SplitDirection::Horizontal => "h",
+                SplitDirection::Vertical => "v",
+            };
+            let _ = writeln!(output, "split {} {}", direction, ratio);
+            save_node(first, output);
+            save_node(second, output);
+        }
+    }
+}
+
+fn load_node(line: &str, lines: &mut Lines) -> Option<DockNode> {
+    if line.starts_with("window ") {
+        return Some(DockNode::Window(ImString::new(&line["window ".len()..])));
+    }
+    let fields = line.split(' ').collect::<Vec<_>>();
+    if fields.len() != 3 || fields[0] != "split" {
+        return None;
+    }
+    let direction = match fields[1] {
+        "h" => SplitDirection::Horizontal,
+        "v" => SplitDirection::Vertical,
+        _ => return None,
+    };
+    let ratio = match fields[2].parse::<f32>() {
+        Ok(ratio) => ratio,
+        Err(_) => return None,
+    };
+    let first = lines.next().and_then(|line| load_node(line, lines));
+    let second = lines.next().and_then(|line| load_node(line, lines));
+    match (first, second) {
+        (Some(first), Some(second)) => Some(DockNode::Split {
+            direction,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }),
+        _ => None,
+    }
+}
+
+fn load_floating(line: &str) -> Option<FloatingWindow> {
+    let fields = line.splitn(6, ' ').collect::<Vec<_>>();
+    if fields.len() != 6 || fields[0] != "floating" {
+        return None;
+    }
+    let numbers = fields[1..5]
+        .iter()
+        .map(|field| field.parse::<f32>())
+        .collect::<Result<Vec<_>, _>>();
+    numbers.ok().map(|numbers| FloatingWindow {
+        name: ImString::new(fields[5]),
+        pos: (numbers[0], numbers[1]),
+        size: (numbers[2], numbers[3]),
+        place: true,
+    })
+}
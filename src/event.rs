@@ -0,0 +1,180 @@
+use sys;
+
+use super::ImMouseButton;
+
+/// A key reported by a platform backend through [`Event::KeyDown`] and [`Event::KeyUp`].
+///
+/// Each key has a fixed index in imgui's `KeysDown` array, and `ImGui::init` sets up the
+/// `KeyMap` for it, so backends never deal with key indices and any key can be queried with
+/// `Ui::is_key_down` and friends.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Key {
+    Tab,
+    LeftArrow,
+    RightArrow,
+    UpArrow,
+    DownArrow,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Insert,
+    Delete,
+    Backspace,
+    Space,
+    Enter,
+    Escape,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Key0,
+    Key1,
+    Key2,
+    Key3,
+    Key4,
+    Key5,
+    Key6,
+    Key7,
+    Key8,
+    Key9,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    Apostrophe,
+    Comma,
+    Minus,
+    Period,
+    Slash,
+    Semicolon,
+    Equal,
+    LeftBracket,
+    Backslash,
+    RightBracket,
+    GraveAccent,
+    CapsLock,
+    ScrollLock,
+    NumLock,
+    PrintScreen,
+    Pause,
+    Keypad0,
+    Keypad1,
+    Keypad2,
+    Keypad3,
+    Keypad4,
+    Keypad5,
+    Keypad6,
+    Keypad7,
+    Keypad8,
+    Keypad9,
+    KeypadDecimal,
+    KeypadDivide,
+    KeypadMultiply,
+    KeypadSubtract,
+    KeypadAdd,
+    KeypadEnter,
+    KeypadEqual,
+    LeftCtrl,
+    RightCtrl,
+    LeftShift,
+    RightShift,
+    LeftAlt,
+    RightAlt,
+    LeftSuper,
+    RightSuper,
+    Menu,
+}
+
+impl Key {
+    /// The index of this key in imgui's `KeysDown` array.
+    pub fn index(self) -> usize { self as usize }
+}
+
+/// A backend-neutral input event, fed to imgui with `ImGui::handle_event`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Event {
+    KeyDown(Key),
+    KeyUp(Key),
+    /// A character of text input.
+    Text(char),
+    /// The mouse cursor moved to the given position in points.
+    MouseMove(f32, f32),
+    /// The mouse cursor left the window.
+    MouseLeave,
+    MouseDown(ImMouseButton),
+    MouseUp(ImMouseButton),
+    /// The mouse wheel scrolled horizontally and vertically, in lines.
+    MouseWheel(f32, f32),
+    /// The window gained or lost focus. Keys and buttons are released when focus is lost.
+    Focus(bool),
+    /// The window was resized, given its size in points and in pixels.
+    Resize((u32, u32), (u32, u32)),
+}
+
+pub(crate) fn configure_key_map(io: &mut sys::ImGuiIO) {
+    let key_map = [
+        (sys::ImGuiKey::Tab, Key::Tab),
+        (sys::ImGuiKey::LeftArrow, Key::LeftArrow),
+        (sys::ImGuiKey::RightArrow, Key::RightArrow),
+        (sys::ImGuiKey::UpArrow, Key::UpArrow),
+        (sys::ImGuiKey::DownArrow, Key::DownArrow),
+        (sys::ImGuiKey::PageUp, Key::PageUp),
+        (sys::ImGuiKey::PageDown, Key::PageDown),
+        (sys::ImGuiKey::Home, Key::Home),
+        (sys::ImGuiKey::End, Key::End),
+        (sys::ImGuiKey::Insert, Key::Insert),
+        (sys::ImGuiKey::Delete, Key::Delete),
+        (sys::ImGuiKey::Backspace, Key::Backspace),
+        (sys::ImGuiKey::Space, Key::Space),
+        (sys::ImGuiKey::Enter, Key::Enter),
+        (sys::ImGuiKey::Escape, Key::Escape),
+        (sys::ImGuiKey::A, Key::A),
+        (sys::ImGuiKey::C, Key::C),
+        (sys::ImGuiKey::V, Key::V),
+        (sys::ImGuiKey::X, Key::X),
+        (sys::ImGuiKey::Y, Key::Y),
+        (sys::ImGuiKey::Z, Key::Z),
+    ];
+    for &(imgui_key, key) in key_map.iter() {
+        io.KeyMap[imgui_key.0 as usize] = key.index() as i32;
+    }
+}
+
+pub(crate) fn set_key_down(io: &mut sys::ImGuiIO, key: Key, down: bool) {
+    io.KeysDown[key.index()] = down;
+    io.KeyCtrl = io.KeysDown[Key::LeftCtrl.index()] || io.KeysDown[Key::RightCtrl.index()];
+    io.KeyShift = io.KeysDown[Key::LeftShift.index()] || io.KeysDown[Key::RightShift.index()];
+    io.KeyAlt = io.KeysDown[Key::LeftAlt.index()] || io.KeysDown[Key::RightAlt.index()];
+    io.KeySuper = io.KeysDown[Key::LeftSuper.index()] || io.KeysDown[Key::RightSuper.index()];
+}
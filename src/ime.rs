@@ -0,0 +1,52 @@
+use std::os::raw::{c_int, c_void};
+use sys;
+
+pub(crate) struct ImeContext {
+    pub(crate) input_pos: Option<(f32, f32)>,
+    callback: Option<Box<FnMut(f32, f32)>>,
+    // The hook and handle ours replaced, put back once the callback is cleared
+    previous: Option<(Option<unsafe extern "C" fn(c_int, c_int)>, *mut c_void)>,
+}
+
+impl ImeContext {
+    pub(crate) fn new() -> ImeContext {
+        ImeContext {
+            input_pos: None,
+            callback: None,
+            previous: None,
+        }
+    }
+    pub(crate) unsafe fn set_callback(&mut self, callback: Box<FnMut(f32, f32)>) {
+        if self.previous.is_none() {
+            let io = &mut *sys::GetIO();
+            self.previous = Some((io.ImeSetInputScreenPosFn, io.ImeWindowHandle));
+            io.ImeSetInputScreenPosFn = Some(set_input_screen_pos);
+            io.ImeWindowHandle = self as *mut ImeContext as *mut c_void;
+        }
+        self.callback = Some(callback);
+    }
+    pub(crate) unsafe fn clear_callback(&mut self) {
+        if let Some((hook, handle)) = self.previous.take() {
+            let io = &mut *sys::GetIO();
+            io.ImeSetInputScreenPosFn = hook;
+            io.ImeWindowHandle = handle;
+        }
+        self.callback = None;
+        self.input_pos = None;
+    }
+}
+
+// imgui passes no user data to this callback, so the context lives in `ImeWindowHandle`,
+// which is otherwise only used by the default Win32 implementation this replaces.
+unsafe extern "C" fn set_input_screen_pos(x: c_int, y: c_int) {
+    let io = &*sys::GetIO();
+    if io.ImeWindowHandle.is_null() {
+        return;
+    }
+    let ctx = &mut *(io.ImeWindowHandle as *mut ImeContext);
+    let pos = (x as f32, y as f32);
+    ctx.input_pos = Some(pos);
+    if let Some(ref mut callback) = ctx.callback {
+        callback(pos.0, pos.1);
+    }
+}
@@ -0,0 +1,127 @@
+use sys;
+use sys::{ImGuiBackendFlags, ImGuiConfigFlags};
+
+use super::{ImMouseButton, Key};
+
+/// A safe view of imgui's `ImGuiIO`, returned by `ImGui::io` and `ImGui::io_mut`.
+///
+/// Configuration fields have a getter and a `set_` method, while fields imgui writes during
+/// the frame only have getters.
+///
+/// # Example
+/// ```rust
+/// # use imgui::*;
+/// let mut imgui = ImGui::init();
+/// {
+///     let io = imgui.io_mut();
+///     io.set_config_flags(ImGuiConfigFlags::NavEnableKeyboard);
+///     io.set_config_input_text_cursor_blink(false);
+/// }
+/// assert!(imgui.io().config_flags().0 & ImGuiConfigFlags::NavEnableKeyboard.0 != 0);
+/// assert!(!imgui.io().config_input_text_cursor_blink());
+/// ```
+#[repr(C)]
+pub struct Io {
+    raw: sys::ImGuiIO,
+}
+
+impl Io {
+    pub(crate) unsafe fn from_raw<'a>(raw: *const sys::ImGuiIO) -> &'a Io { &*(raw as *const Io) }
+    pub(crate) unsafe fn from_raw_mut<'a>(raw: *mut sys::ImGuiIO) -> &'a mut Io {
+        &mut *(raw as *mut Io)
+    }
+
+    // Configuration
+
+    pub fn config_flags(&self) -> ImGuiConfigFlags { self.raw.ConfigFlags }
+    pub fn set_config_flags(&mut self, flags: ImGuiConfigFlags) { self.raw.ConfigFlags = flags; }
+    pub fn backend_flags(&self) -> ImGuiBackendFlags { self.raw.BackendFlags }
+    pub fn set_backend_flags(&mut self, flags: ImGuiBackendFlags) { self.raw.BackendFlags = flags; }
+    pub fn ini_saving_rate(&self) -> f32 { self.raw.IniSavingRate }
+    pub fn set_ini_saving_rate(&mut self, value: f32) { self.raw.IniSavingRate = value; }
+    pub fn mouse_double_click_time(&self) -> f32 { self.raw.MouseDoubleClickTime }
+    pub fn set_mouse_double_click_time(&mut self, value: f32) {
+        self.raw.MouseDoubleClickTime = value;
+    }
+    pub fn mouse_double_click_max_dist(&self) -> f32 { self.raw.MouseDoubleClickMaxDist }
+    pub fn set_mouse_double_click_max_dist(&mut self, value: f32) {
+        self.raw.MouseDoubleClickMaxDist = value;
+    }
+    pub fn mouse_drag_threshold(&self) -> f32 { self.raw.MouseDragThreshold }
+    pub fn set_mouse_drag_threshold(&mut self, value: f32) { self.raw.MouseDragThreshold = value; }
+    pub fn key_repeat_delay(&self) -> f32 { self.raw.KeyRepeatDelay }
+    pub fn set_key_repeat_delay(&mut self, value: f32) { self.raw.KeyRepeatDelay = value; }
+    pub fn key_repeat_rate(&self) -> f32 { self.raw.KeyRepeatRate }
+    pub fn set_key_repeat_rate(&mut self, value: f32) { self.raw.KeyRepeatRate = value; }
+    pub fn font_global_scale(&self) -> f32 { self.raw.FontGlobalScale }
+    pub fn set_font_global_scale(&mut self, value: f32) { self.raw.FontGlobalScale = value; }
+    pub fn font_allow_user_scaling(&self) -> bool { self.raw.FontAllowUserScaling }
+    pub fn set_font_allow_user_scaling(&mut self, value: bool) {
+        self.raw.FontAllowUserScaling = value;
+    }
+    pub fn display_framebuffer_scale(&self) -> (f32, f32) {
+        self.raw.DisplayFramebufferScale.into()
+    }
+    pub fn set_display_framebuffer_scale(&mut self, value: (f32, f32)) {
+        self.raw.DisplayFramebufferScale = value.into();
+    }
+    /// Use macOS conventions: Cmd instead of Ctrl for shortcuts, Alt to move by words and
+    /// Cmd+Left/Right to move to the start or end of a line.
+    pub fn config_mac_osx_behaviors(&self) -> bool { self.raw.ConfigMacOSXBehaviors }
+    pub fn set_config_mac_osx_behaviors(&mut self, value: bool) {
+        self.raw.ConfigMacOSXBehaviors = value;
+    }
+    pub fn config_input_text_cursor_blink(&self) -> bool { self.raw.ConfigInputTextCursorBlink }
+    pub fn set_config_input_text_cursor_blink(&mut self, value: bool) {
+        self.raw.ConfigInputTextCursorBlink = value;
+    }
+    /// Allow resizing windows from their edges and lower-left corner, which needs the
+    /// backend to honor the resize mouse cursors.
+    pub fn config_resize_windows_from_edges(&self) -> bool { self.raw.ConfigResizeWindowsFromEdges }
+    pub fn set_config_resize_windows_from_edges(&mut self, value: bool) {
+        self.raw.ConfigResizeWindowsFromEdges = value;
+    }
+
+    // Input state
+
+    pub fn display_size(&self) -> (f32, f32) { self.raw.DisplaySize.into() }
+    pub fn delta_time(&self) -> f32 { self.raw.DeltaTime }
+    pub fn mouse_pos(&self) -> (f32, f32) { self.raw.MousePos.into() }
+    pub fn mouse_down(&self, button: ImMouseButton) -> bool { self.raw.MouseDown[button as usize] }
+    pub fn mouse_wheel(&self) -> f32 { self.raw.MouseWheel }
+    pub fn mouse_wheel_h(&self) -> f32 { self.raw.MouseWheelH }
+    pub fn mouse_draw_cursor(&self) -> bool { self.raw.MouseDrawCursor }
+    pub fn set_mouse_draw_cursor(&mut self, value: bool) { self.raw.MouseDrawCursor = value; }
+    pub fn key_ctrl(&self) -> bool { self.raw.KeyCtrl }
+    pub fn key_shift(&self) -> bool { self.raw.KeyShift }
+    pub fn key_alt(&self) -> bool { self.raw.KeyAlt }
+    pub fn key_super(&self) -> bool { self.raw.KeySuper }
+    pub fn key_down(&self, key: Key) -> bool { self.raw.KeysDown[key.index()] }
+
+    // Output
+
+    pub fn want_capture_mouse(&self) -> bool { self.raw.WantCaptureMouse }
+    pub fn want_capture_keyboard(&self) -> bool { self.raw.WantCaptureKeyboard }
+    pub fn want_text_input(&self) -> bool { self.raw.WantTextInput }
+    pub fn want_set_mouse_pos(&self) -> bool { self.raw.WantSetMousePos }
+    pub fn want_save_ini_settings(&self) -> bool { self.raw.WantSaveIniSettings }
+    pub fn nav_active(&self) -> bool { self.raw.NavActive }
+    pub fn nav_visible(&self) -> bool { self.raw.NavVisible }
+    pub fn framerate(&self) -> f32 { self.raw.Framerate }
+    pub fn metrics_render_vertices(&self) -> i32 { self.raw.MetricsRenderVertices }
+    pub fn metrics_render_indices(&self) -> i32 { self.raw.MetricsRenderIndices }
+    pub fn metrics_render_windows(&self) -> i32 { self.raw.MetricsRenderWindows }
+    pub fn metrics_active_windows(&self) -> i32 { self.raw.MetricsActiveWindows }
+    pub fn metrics_active_allocations(&self) -> i32 { self.raw.MetricsActiveAllocations }
+    pub fn mouse_delta(&self) -> (f32, f32) { self.raw.MouseDelta.into() }
+    /// Returns the position where `button` was last clicked.
+    pub fn mouse_clicked_pos(&self, button: ImMouseButton) -> (f32, f32) {
+        self.raw.MouseClickedPos[button as usize].into()
+    }
+    /// Returns how long `button` has been held, in seconds, or a negative value if it is up.
+    pub fn mouse_down_duration(&self, button: ImMouseButton) -> f32 {
+        self.raw.MouseDownDuration[button as usize]
+    }
+    /// Returns how long `key` has been held, in seconds, or a negative value if it is up.
+    pub fn keys_down_duration(&self, key: Key) -> f32 { self.raw.KeysDownDuration[key.index()] }
+}
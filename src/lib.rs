@@ -1,5 +1,6 @@
 pub extern crate imgui_sys as sys;
 
+use std::cell::Cell;
 use std::ffi::CStr;
 use std::mem;
 use std::os::raw::{c_char, c_float, c_int, c_uchar, c_void};
@@ -8,23 +9,33 @@ use std::slice;
 use std::str;
 
 pub use child_frame::ChildFrame;
+pub use child_window::{ChildWindow, ChildWindowId, ChildWindowToken};
+pub use clipboard::{ClipboardBackend, MemoryClipboard};
 pub use color_editors::{
     ColorButton, ColorEdit, ColorEditMode, ColorFormat, ColorPicker, ColorPickerMode, ColorPreview,
     EditableColor,
 };
+pub use dock::{DockLayout, DockSide};
 pub use drag::{
     DragFloat, DragFloat2, DragFloat3, DragFloat4, DragFloatRange2, DragInt, DragInt2, DragInt3,
     DragInt4, DragIntRange2,
 };
+pub use event::{Event, Key};
 pub use fonts::{FontGlyphRange, ImFont, ImFontAtlas, ImFontConfig};
 pub use input::{
     InputFloat, InputFloat2, InputFloat3, InputFloat4, InputInt, InputInt2, InputInt3, InputInt4,
     InputText, InputTextMultiline,
 };
+pub use io::Io;
 pub use menus::{Menu, MenuItem};
+pub use navigation::GamepadState;
+pub use node_editor::{Link, Node, NodeEditor, NodeEditorState, NodeEvent, Pin};
+pub use plot::{Plot, PlotSeries};
 pub use plothistogram::PlotHistogram;
 pub use plotlines::PlotLines;
+pub use plotvalues::PlotValues;
 pub use progressbar::ProgressBar;
+pub use shortcuts::{Shortcut, ShortcutScope, Shortcuts};
 pub use sliders::{
     SliderFloat, SliderFloat2, SliderFloat3, SliderFloat4, SliderInt, SliderInt2, SliderInt3,
     SliderInt4,
@@ -32,22 +43,40 @@ pub use sliders::{
 pub use string::{ImStr, ImString};
 pub use style::StyleVar;
 pub use sys::*;
-pub use trees::{CollapsingHeader, TreeNode};
+pub use tab_bar::{Tab, TabBar, TabBarResult};
+pub use tree_view::{TreeSource, TreeView};
+pub use trees::{CollapsingHeader, TreeNode, TreeNodeResult, TreeNodeToken};
 pub use window::Window;
-pub use window_draw_list::{ChannelsSplit, ImColor, WindowDrawList};
+pub use window_draw_list::{ChannelsSplit, ImColor, Path, Polyline, Quad, WindowDrawList};
+
+use clipboard::ClipboardContext;
+use ime::ImeContext;
 
 mod child_frame;
+mod child_window;
+mod clipboard;
 mod color_editors;
+mod dock;
 mod drag;
+mod event;
 mod fonts;
+mod ime;
 mod input;
+mod io;
 mod menus;
+mod navigation;
+mod node_editor;
+mod plot;
 mod plothistogram;
 mod plotlines;
+mod plotvalues;
 mod progressbar;
+mod shortcuts;
 mod sliders;
 mod string;
 mod style;
+mod tab_bar;
+mod tree_view;
 mod trees;
 mod window;
 mod window_draw_list;
@@ -58,6 +87,11 @@ pub struct ImGui {
     ini_filename: Option<ImString>,
     log_filename: Option<ImString>,
     context: *mut sys::ImGuiContext,
+    hidpi_font_scaling: bool,
+    font_dpi_scale: f32,
+    clipboard_ctx: Option<Box<ClipboardContext>>,
+    ime_ctx: Box<ImeContext>,
+    disabled_depth: Cell<u32>,
 }
 
 #[macro_export]
@@ -75,9 +109,22 @@ macro_rules! im_str {
     })
 }
 
+/// Pixel format of the font atlas texture data handed to renderers.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TextureFormat {
+    /// Four bytes per pixel: white RGB with the glyph coverage in alpha.
+    Rgba32,
+    /// One byte per pixel holding only the glyph coverage.
+    ///
+    /// Uses a quarter of the memory of `Rgba32`. Renderers are expected to expand it to
+    /// white-with-alpha when sampling.
+    Alpha8,
+}
+
 pub struct TextureHandle<'a> {
     pub width: u32,
     pub height: u32,
+    pub format: TextureFormat,
     pub pixels: &'a [c_uchar],
 }
 
@@ -110,39 +157,69 @@ impl ImGui {
         unsafe {
             let ctx = sys::CreateContext(ptr::null_mut());
             sys::SetCurrentContext(ctx);
+            event::configure_key_map(&mut *sys::GetIO());
             ImGui {
                 ini_filename: None,
                 log_filename: None,
                 context: ctx,
+                hidpi_font_scaling: false,
+                font_dpi_scale: 1.0,
+                clipboard_ctx: None,
+                ime_ctx: Box::new(ImeContext::new()),
+                disabled_depth: Cell::new(0),
             }
         }
     }
-    fn io(&self) -> &sys::ImGuiIO { unsafe { &*sys::GetIO() } }
-    fn io_mut(&mut self) -> &mut sys::ImGuiIO { unsafe { &mut *sys::GetIO() } }
+    fn sys_io(&self) -> &sys::ImGuiIO { unsafe { &*sys::GetIO() } }
+    fn sys_io_mut(&mut self) -> &mut sys::ImGuiIO { unsafe { &mut *sys::GetIO() } }
+    pub fn io(&self) -> &Io { unsafe { Io::from_raw(sys::GetIO()) } }
+    pub fn io_mut(&mut self) -> &mut Io { unsafe { Io::from_raw_mut(sys::GetIO()) } }
     pub fn style(&self) -> &ImGuiStyle { unsafe { &*sys::GetStyle() } }
     pub fn style_mut(&mut self) -> &mut ImGuiStyle { unsafe { &mut *sys::GetStyle() } }
-    pub fn fonts(&mut self) -> ImFontAtlas { unsafe { ImFontAtlas::from_ptr(self.io_mut().Fonts) } }
+    pub fn fonts(&mut self) -> ImFontAtlas {
+        unsafe { ImFontAtlas::from_ptr(self.sys_io_mut().Fonts) }
+    }
     pub fn prepare_texture<'a, F, T>(&mut self, f: F) -> T
     where
         F: FnOnce(TextureHandle<'a>) -> T,
     {
-        let io = self.io();
+        self.prepare_texture_with_format(TextureFormat::Rgba32, f)
+    }
+    /// Builds the font atlas and passes its texture data in the requested `format` to `f`.
+    ///
+    /// Only the requested format is generated, so `TextureFormat::Alpha8` avoids allocating
+    /// the much larger RGBA32 copy of the atlas.
+    pub fn prepare_texture_with_format<'a, F, T>(&mut self, format: TextureFormat, f: F) -> T
+    where
+        F: FnOnce(TextureHandle<'a>) -> T,
+    {
+        let io = self.sys_io();
         let mut pixels: *mut c_uchar = ptr::null_mut();
         let mut width: c_int = 0;
         let mut height: c_int = 0;
         let mut bytes_per_pixel: c_int = 0;
         unsafe {
             sys::BuildFontAtlas(io.Fonts, sys::RasterizerFlags::ForceAutoHint.0 as _);
-            sys::ImFontAtlas_GetTexDataAsRGBA32(
-                io.Fonts,
-                &mut pixels,
-                &mut width,
-                &mut height,
-                &mut bytes_per_pixel,
-            );
+            match format {
+                TextureFormat::Rgba32 => sys::ImFontAtlas_GetTexDataAsRGBA32(
+                    io.Fonts,
+                    &mut pixels,
+                    &mut width,
+                    &mut height,
+                    &mut bytes_per_pixel,
+                ),
+                TextureFormat::Alpha8 => sys::ImFontAtlas_GetTexDataAsAlpha8(
+                    io.Fonts,
+                    &mut pixels,
+                    &mut width,
+                    &mut height,
+                    &mut bytes_per_pixel,
+                ),
+            }
             f(TextureHandle {
                 width: width as u32,
                 height: height as u32,
+                format,
                 pixels: slice::from_raw_parts(pixels, (width * height * bytes_per_pixel) as usize),
             })
         }
@@ -150,7 +227,7 @@ impl ImGui {
     pub fn set_texture_id(&mut self, value: usize) { self.fonts().set_texture_id(value); }
     pub fn set_ini_filename(&mut self, value: Option<ImString>) {
         {
-            let io = self.io_mut();
+            let io = self.sys_io_mut();
             io.IniFilename = match value {
                 Some(ref x) => x.as_ptr(),
                 None => ptr::null(),
@@ -158,9 +235,57 @@ impl ImGui {
         }
         self.ini_filename = value;
     }
+    /// Loads window positions and other settings from ini data held in memory.
+    ///
+    /// This must be called before the first frame. Combine it with `set_ini_filename(None)` so
+    /// that `imgui.ini` is neither read nor written.
+    ///
+    /// ```rust,no_run
+    /// # use imgui::ImGui;
+    /// let mut imgui = ImGui::init();
+    /// imgui.set_ini_filename(None);
+    /// imgui.load_ini_settings("[Window][Debug##Default]\nPos=60,60\nSize=400,400\n");
+    /// ```
+    pub fn load_ini_settings(&mut self, data: &str) {
+        if data.is_empty() {
+            return;
+        }
+        unsafe { sys::LoadIniSettingsFromMemory(data.as_ptr() as *const c_char, data.len()) }
+    }
+    /// Serializes the current settings to ini data, and clears `want_save_ini_settings`.
+    ///
+    /// ```rust
+    /// # use imgui::ImGui;
+    /// let mut imgui = ImGui::init();
+    /// imgui.set_ini_filename(None);
+    /// imgui.load_ini_settings("[Window][Tools]\nPos=60,60\nSize=300,200\n");
+    /// # // Set as imgui does once changed settings are due for saving
+    /// # unsafe { (*imgui::sys::GetIO()).WantSaveIniSettings = true };
+    /// if imgui.want_save_ini_settings() {
+    ///     let data = imgui.save_ini_settings();
+    ///     assert!(data.contains("[Window][Tools]\nPos=60,60\nSize=300,200\n"));
+    /// }
+    /// assert!(!imgui.want_save_ini_settings());
+    /// ```
+    pub fn save_ini_settings(&mut self) -> String {
+        let data = unsafe {
+            let mut size = 0;
+            let data = sys::SaveIniSettingsToMemory(&mut size);
+            let bytes = slice::from_raw_parts(data as *const u8, size);
+            String::from_utf8_lossy(bytes).into_owned()
+        };
+        // imgui leaves resetting the flag to the application
+        self.sys_io_mut().WantSaveIniSettings = false;
+        data
+    }
+    /// Returns true when settings changed and `ini_saving_rate` seconds went by since, so the
+    /// application should call `save_ini_settings` and persist the result.
+    ///
+    /// Only reported when no ini filename is set; otherwise imgui saves to the file itself.
+    pub fn want_save_ini_settings(&self) -> bool { self.sys_io().WantSaveIniSettings }
     pub fn set_log_filename(&mut self, value: Option<ImString>) {
         {
-            let io = self.io_mut();
+            let io = self.sys_io_mut();
             io.LogFilename = match value {
                 Some(ref x) => x.as_ptr(),
                 None => ptr::null(),
@@ -169,76 +294,202 @@ impl ImGui {
         self.log_filename = value;
     }
     pub fn set_ini_saving_rate(&mut self, value: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.IniSavingRate = value;
     }
     pub fn set_font_global_scale(&mut self, value: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.FontGlobalScale = value;
     }
+    /// Enables or disables HiDPI font scaling.
+    ///
+    /// When enabled, `update_hidpi_scale` rasterizes the fonts at `size_pixels * scale` and
+    /// divides the font global scale by `scale`, so text keeps its size in points but stays
+    /// sharp on high density displays. Style sizes are expressed in points and are already
+    /// scaled by the display framebuffer scale when rendering, so they are left untouched.
+    pub fn set_hidpi_font_scaling(&mut self, value: bool) { self.hidpi_font_scaling = value; }
+    pub fn hidpi_font_scaling(&self) -> bool { self.hidpi_font_scaling }
+    /// The scale factor the font atlas is currently rasterized at.
+    pub fn font_dpi_scale(&self) -> f32 { self.font_dpi_scale }
+    /// Rebuilds the font atlas if the given DPI scale factor differs from the one the fonts
+    /// are currently rasterized at.
+    ///
+    /// This must be called before `frame`, typically with the value reported by
+    /// `display_framebuffer_scale` or the windowing library's HiDPI factor. If HiDPI font
+    /// scaling is disabled, the fonts are brought back to a scale of 1.
+    ///
+    /// Returns true if the atlas was rebuilt, in which case the renderer must reload its font
+    /// texture before the next frame is rendered.
+    ///
+    /// ```rust,no_run
+    /// # use imgui::ImGui;
+    /// let mut imgui = ImGui::init();
+    /// imgui.set_hidpi_font_scaling(true);
+    /// if imgui.update_hidpi_scale(2.0) {
+    ///     // renderer.reload_font_texture(&mut imgui)
+    /// }
+    /// ```
+    pub fn update_hidpi_scale(&mut self, scale: f32) -> bool {
+        let scale = if self.hidpi_font_scaling && scale > 0.0 {
+            scale
+        } else {
+            1.0
+        };
+        if scale == self.font_dpi_scale {
+            return false;
+        }
+        let ratio = scale / self.font_dpi_scale;
+        {
+            let io = self.sys_io_mut();
+            unsafe {
+                let atlas = &mut *io.Fonts;
+                for i in 0..atlas.ConfigData.Size as isize {
+                    let config = &mut *atlas.ConfigData.Data.offset(i);
+                    config.SizePixels *= ratio;
+                    config.GlyphExtraSpacing.x *= ratio;
+                    config.GlyphExtraSpacing.y *= ratio;
+                    config.GlyphOffset.x *= ratio;
+                    config.GlyphOffset.y *= ratio;
+                }
+                sys::ImFontAtlas_ClearTexData(atlas);
+                sys::BuildFontAtlas(atlas, sys::RasterizerFlags::ForceAutoHint.0 as _);
+            }
+            io.FontGlobalScale /= ratio;
+        }
+        self.font_dpi_scale = scale;
+        true
+    }
+    /// Replaces imgui's clipboard handling with the given backend.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use imgui::*;
+    /// let mut imgui = ImGui::init();
+    /// imgui.set_clipboard_backend(MemoryClipboard::new());
+    /// imgui.set_clipboard_text("copied");
+    /// assert_eq!(imgui.clipboard_text(), Some("copied".to_owned()));
+    /// ```
+    pub fn set_clipboard_backend<T: ClipboardBackend + 'static>(&mut self, backend: T) {
+        let mut ctx = Box::new(ClipboardContext::new(Box::new(backend)));
+        {
+            let io = self.sys_io_mut();
+            io.GetClipboardTextFn = Some(clipboard::get_clipboard_text);
+            io.SetClipboardTextFn = Some(clipboard::set_clipboard_text);
+            io.ClipboardUserData = &mut *ctx as *mut ClipboardContext as *mut c_void;
+        }
+        self.clipboard_ctx = Some(ctx);
+    }
+    /// Returns the clipboard contents as seen by imgui.
+    pub fn clipboard_text(&self) -> Option<String> {
+        unsafe {
+            let text = sys::GetClipboardText();
+            if text.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(text).to_string_lossy().into_owned())
+            }
+        }
+    }
+    pub fn set_clipboard_text(&mut self, text: &str) {
+        let text = ImString::new(text);
+        unsafe { sys::SetClipboardText(text.as_ptr()) }
+    }
+    /// Registers a closure called with the screen position of the text input caret, in points,
+    /// whenever it moves. Platform layers use it to place the IME candidate window.
+    ///
+    /// The callback replaces imgui's own IME handling until `clear_ime_position_callback`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use imgui::*;
+    /// let mut imgui = ImGui::init();
+    /// imgui.set_ime_position_callback(|x, y| {
+    ///     println!("IME candidate window goes to ({}, {})", x, y);
+    /// });
+    /// ```
+    pub fn set_ime_position_callback<F: FnMut(f32, f32) + 'static>(&mut self, callback: F) {
+        unsafe { self.ime_ctx.set_callback(Box::new(callback)) }
+    }
+    /// Removes the closure registered with `set_ime_position_callback`, giving the IME back to
+    /// imgui's own handling.
+    pub fn clear_ime_position_callback(&mut self) { unsafe { self.ime_ctx.clear_callback() } }
+    /// The last screen position of the text input caret reported by imgui while an IME position
+    /// callback is registered, if any.
+    pub fn ime_input_pos(&self) -> Option<(f32, f32)> { self.ime_ctx.input_pos }
+    /// Whether a text input widget is active, so the platform should enable IME.
+    pub fn want_text_input(&self) -> bool { self.sys_io().WantTextInput }
     pub fn set_mouse_double_click_time(&mut self, value: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.MouseDoubleClickTime = value;
     }
     pub fn set_mouse_double_click_max_dist(&mut self, value: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.MouseDoubleClickMaxDist = value;
     }
     pub fn set_mouse_drag_threshold(&mut self, value: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.MouseDragThreshold = value;
     }
     pub fn set_key_repeat_delay(&mut self, value: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.KeyRepeatDelay = value;
     }
     pub fn set_key_repeat_rate(&mut self, value: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.KeyRepeatRate = value;
     }
     pub fn display_size(&self) -> (f32, f32) {
-        let io = self.io();
+        let io = self.sys_io();
         (io.DisplaySize.x, io.DisplaySize.y)
     }
     pub fn display_framebuffer_scale(&self) -> (f32, f32) {
-        let io = self.io();
+        let io = self.sys_io();
         (io.DisplayFramebufferScale.x, io.DisplayFramebufferScale.y)
     }
     pub fn mouse_pos(&self) -> (f32, f32) {
-        let io = self.io();
+        let io = self.sys_io();
         (io.MousePos.x, io.MousePos.y)
     }
     pub fn set_mouse_pos(&mut self, x: f32, y: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.MousePos.x = x;
         io.MousePos.y = y;
     }
     /// Get mouse's position's delta between the current and the last frame.
     pub fn mouse_delta(&self) -> (f32, f32) {
-        let io = self.io();
+        let io = self.sys_io();
         (io.MouseDelta.x, io.MouseDelta.y)
     }
     pub fn set_mouse_down(&mut self, states: &[bool; 5]) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.MouseDown = *states;
     }
     pub fn set_mouse_wheel(&mut self, value: f32) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.MouseWheel = value;
     }
     /// Get mouse wheel delta
     pub fn mouse_wheel(&self) -> f32 {
-        let io = self.io();
+        let io = self.sys_io();
         io.MouseWheel
     }
+    pub fn set_mouse_wheel_h(&mut self, value: f32) {
+        let io = self.sys_io_mut();
+        io.MouseWheelH = value;
+    }
+    /// Get horizontal mouse wheel delta
+    pub fn mouse_wheel_h(&self) -> f32 {
+        let io = self.sys_io();
+        io.MouseWheelH
+    }
     /// Set to `true` to have ImGui draw the cursor in software.
     /// If `false`, the OS cursor is used (default to `false`).
     pub fn set_mouse_draw_cursor(&mut self, value: bool) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.MouseDrawCursor = value;
     }
     pub fn mouse_draw_cursor(&self) -> bool {
-        let io = self.io();
+        let io = self.sys_io();
         io.MouseDrawCursor
     }
     /// Set currently displayed cursor.
@@ -274,39 +525,43 @@ impl ImGui {
         unsafe { sys::IsMouseReleased(button as c_int) }
     }
     pub fn key_ctrl(&self) -> bool {
-        let io = self.io();
+        let io = self.sys_io();
         io.KeyCtrl
     }
     pub fn set_key_ctrl(&mut self, value: bool) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.KeyCtrl = value;
     }
     pub fn key_shift(&self) -> bool {
-        let io = self.io();
+        let io = self.sys_io();
         io.KeyShift
     }
     pub fn set_key_shift(&mut self, value: bool) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.KeyShift = value;
     }
     pub fn key_alt(&self) -> bool {
-        let io = self.io();
+        let io = self.sys_io();
         io.KeyAlt
     }
     pub fn set_key_alt(&mut self, value: bool) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.KeyAlt = value;
     }
+    pub fn key_super(&self) -> bool {
+        let io = self.sys_io();
+        io.KeySuper
+    }
     pub fn set_key_super(&mut self, value: bool) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.KeySuper = value;
     }
     pub fn set_key(&mut self, key: u8, pressed: bool) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.KeysDown[key as usize] = pressed;
     }
     pub fn set_imgui_key(&mut self, key: ImGuiKey, mapping: u8) {
-        let io = self.io_mut();
+        let io = self.sys_io_mut();
         io.KeyMap[key.0 as usize] = mapping as i32;
     }
     /// Map [`ImGuiKey`] values into user's key index
@@ -315,6 +570,9 @@ impl ImGui {
     }
     /// Return whether specific key is being held
     ///
+    /// Backends feeding imgui with `handle_event` can query keys directly with
+    /// `Ui::is_key_down`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -342,12 +600,109 @@ impl ImGui {
         let mut buf = [0; 5];
         character.encode_utf8(&mut buf);
         unsafe {
-            sys::ImGuiIO_AddInputCharactersUTF8(self.io_mut(), buf.as_ptr() as *const _);
+            sys::ImGuiIO_AddInputCharactersUTF8(self.sys_io_mut(), buf.as_ptr() as *const _);
+        }
+    }
+    /// Enables keyboard navigation with arrow keys, tab, space, enter and escape.
+    pub fn set_nav_enable_keyboard(&mut self, value: bool) {
+        let io = self.sys_io_mut();
+        io.ConfigFlags.set(ImGuiConfigFlags::NavEnableKeyboard, value);
+    }
+    /// Enables gamepad navigation. The backend feeds the gamepad with `set_gamepad` or
+    /// `set_nav_input` every frame.
+    pub fn set_nav_enable_gamepad(&mut self, value: bool) {
+        let io = self.sys_io_mut();
+        io.ConfigFlags.set(ImGuiConfigFlags::NavEnableGamepad, value);
+        io.BackendFlags.set(ImGuiBackendFlags::HasGamepad, value);
+    }
+    /// Lets navigation move the mouse cursor, for when the backend can't provide a mouse. The
+    /// backend must then move the OS cursor when `want_set_mouse_pos` is true.
+    pub fn set_nav_enable_set_mouse_pos(&mut self, value: bool) {
+        let io = self.sys_io_mut();
+        io.ConfigFlags.set(ImGuiConfigFlags::NavEnableSetMousePos, value);
+        io.BackendFlags.set(ImGuiBackendFlags::HasSetMousePos, value);
+    }
+    /// Stops keyboard navigation from setting `want_capture_keyboard`.
+    pub fn set_nav_no_capture_keyboard(&mut self, value: bool) {
+        let io = self.sys_io_mut();
+        io.ConfigFlags.set(ImGuiConfigFlags::NavNoCaptureKeyboard, value);
+    }
+    /// Sets the value of a navigation input, between 0.0 and 1.0.
+    pub fn set_nav_input(&mut self, input: ImGuiNavInput, value: f32) {
+        let io = self.sys_io_mut();
+        io.NavInputs[input.0 as usize] = value;
+    }
+    /// Feeds the gamepad state to the navigation inputs.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use imgui::*;
+    /// let mut imgui = ImGui::init();
+    /// imgui.set_nav_enable_gamepad(true);
+    /// imgui.set_gamepad(&GamepadState {
+    ///     left_stick: (0.0, 1.0),
+    ///     a: true,
+    ///     ..GamepadState::default()
+    /// });
+    /// ```
+    pub fn set_gamepad(&mut self, gamepad: &GamepadState) {
+        let io = self.sys_io_mut();
+        gamepad.apply(&mut io.NavInputs);
+    }
+    /// Whether keyboard or gamepad navigation is currently moving the focus.
+    pub fn nav_active(&self) -> bool { self.sys_io().NavActive }
+    /// Whether the navigation cursor is visible.
+    pub fn nav_visible(&self) -> bool { self.sys_io().NavVisible }
+    /// Whether navigation moved the mouse, in which case the backend should move the OS cursor
+    /// to `mouse_pos`.
+    pub fn want_set_mouse_pos(&self) -> bool { self.sys_io().WantSetMousePos }
+    /// Feeds an input event from the platform backend to imgui.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use imgui::*;
+    /// let mut imgui = ImGui::init();
+    /// imgui.handle_event(Event::MouseMove(10.0, 20.0));
+    /// imgui.handle_event(Event::KeyDown(Key::LeftCtrl));
+    /// imgui.handle_event(Event::MouseDown(ImMouseButton::Left));
+    /// assert_eq!(imgui.mouse_pos(), (10.0, 20.0));
+    /// assert!(imgui.key_ctrl());
+    /// ```
+    pub fn handle_event(&mut self, event: Event) {
+        let io = self.sys_io_mut();
+        match event {
+            Event::KeyDown(key) => event::set_key_down(io, key, true),
+            Event::KeyUp(key) => event::set_key_down(io, key, false),
+            Event::Text(ch) => {
+                let mut buf = [0; 5];
+                ch.encode_utf8(&mut buf);
+                unsafe { sys::ImGuiIO_AddInputCharactersUTF8(io, buf.as_ptr() as *const _) }
+            }
+            Event::MouseMove(x, y) => io.MousePos = ImVec2::new(x, y),
+            Event::MouseLeave => io.MousePos = ImVec2::new(-std::f32::MAX, -std::f32::MAX),
+            Event::MouseDown(button) => io.MouseDown[button as usize] = true,
+            Event::MouseUp(button) => io.MouseDown[button as usize] = false,
+            Event::MouseWheel(x, y) => {
+                io.MouseWheelH += x;
+                io.MouseWheel += y;
+            }
+            Event::Focus(true) => (),
+            Event::Focus(false) => {
+                io.KeysDown = [false; 512];
+                io.MouseDown = [false; 5];
+                io.KeyCtrl = false;
+                io.KeyShift = false;
+                io.KeyAlt = false;
+                io.KeySuper = false;
+            }
+            Event::Resize(size_points, size_pixels) => {
+                set_display_size(io, size_points, size_pixels)
+            }
         }
     }
     pub fn get_time(&self) -> f32 { unsafe { sys::GetTime() } }
     pub fn get_frame_count(&self) -> i32 { unsafe { sys::GetFrameCount() } }
-    pub fn get_frame_rate(&self) -> f32 { self.io().Framerate }
+    pub fn get_frame_rate(&self) -> f32 { self.sys_io().Framerate }
     pub fn frame<'ui, 'a: 'ui>(
         &'a mut self,
         size_points: (u32, u32),
@@ -355,19 +710,8 @@ impl ImGui {
         delta_time: f32,
     ) -> Ui<'ui> {
         {
-            let io = self.io_mut();
-            io.DisplaySize.x = size_points.0 as c_float;
-            io.DisplaySize.y = size_points.1 as c_float;
-            io.DisplayFramebufferScale.x = if size_points.0 > 0 {
-                size_pixels.0 as c_float / size_points.0 as c_float
-            } else {
-                0.0
-            };
-            io.DisplayFramebufferScale.y = if size_points.1 > 0 {
-                size_pixels.1 as c_float / size_points.1 as c_float
-            } else {
-                0.0
-            };
+            let io = self.sys_io_mut();
+            set_display_size(io, size_points, size_pixels);
             io.DeltaTime = delta_time;
         }
         unsafe {
@@ -380,6 +724,21 @@ impl ImGui {
     }
 }
 
+fn set_display_size(io: &mut sys::ImGuiIO, size_points: (u32, u32), size_pixels: (u32, u32)) {
+    io.DisplaySize.x = size_points.0 as c_float;
+    io.DisplaySize.y = size_points.1 as c_float;
+    io.DisplayFramebufferScale.x = if size_points.0 > 0 {
+        size_pixels.0 as c_float / size_points.0 as c_float
+    } else {
+        0.0
+    };
+    io.DisplayFramebufferScale.y = if size_points.1 > 0 {
+        size_pixels.1 as c_float / size_points.1 as c_float
+    } else {
+        0.0
+    };
+}
+
 impl Drop for ImGui {
     fn drop(&mut self) {
         unsafe {
@@ -462,36 +821,45 @@ static FMT: &'static [u8] = b"%s\0";
 
 fn fmt_ptr() -> *const c_char { FMT.as_ptr() as *const c_char }
 
+// The size of text drawn with the current font, for strings which are not `ImStr`
+fn text_size(text: &str) -> ImVec2 {
+    unsafe {
+        let start = text.as_ptr() as *const c_char;
+        let end = start.offset(text.len() as isize);
+        sys::CalcTextSize(start, end, false, -1.0)
+    }
+}
+
 impl<'ui> Ui<'ui> {
     pub fn imgui(&self) -> &ImGui { self.imgui }
     pub fn want_capture_mouse(&self) -> bool {
-        let io = self.imgui.io();
+        let io = self.imgui.sys_io();
         io.WantCaptureMouse
     }
     pub fn want_capture_keyboard(&self) -> bool {
-        let io = self.imgui.io();
+        let io = self.imgui.sys_io();
         io.WantCaptureKeyboard
     }
     pub fn framerate(&self) -> f32 {
-        let io = self.imgui.io();
+        let io = self.imgui.sys_io();
         io.Framerate
     }
     /*
     pub fn metrics_allocs(&self) -> i32 {
-        let io = self.imgui.io();
+        let io = self.imgui.sys_io();
         io.MetricsAllocs
     }
     */
     pub fn metrics_render_vertices(&self) -> i32 {
-        let io = self.imgui.io();
+        let io = self.imgui.sys_io();
         io.MetricsRenderVertices
     }
     pub fn metrics_render_indices(&self) -> i32 {
-        let io = self.imgui.io();
+        let io = self.imgui.sys_io();
         io.MetricsRenderIndices
     }
     pub fn metrics_active_windows(&self) -> i32 {
-        let io = self.imgui.io();
+        let io = self.imgui.sys_io();
         io.MetricsActiveWindows
     }
     pub fn render<F, E>(self, f: F) -> Result<(), E>
@@ -542,6 +910,75 @@ impl<'ui> Ui<'ui> {
     pub fn window<'p>(&self, name: &'p ImStr) -> Window<'ui, 'p> { Window::new(self, name) }
     /// Get current window's size in pixels
     pub fn get_window_size(&self) -> (f32, f32) { unsafe { sys::GetWindowSize().into() } }
+    /// Get current window's position in screen coordinates
+    pub fn get_window_pos(&self) -> (f32, f32) { unsafe { sys::GetWindowPos().into() } }
+    /// Get the maximum of current window's content region, in window coordinates. This is the
+    /// content size if set, or the window size minus padding.
+    pub fn get_content_region_max(&self) -> (f32, f32) {
+        unsafe { sys::GetContentRegionMax().into() }
+    }
+    /// Get the minimum of current window's content region, in window coordinates
+    pub fn get_window_content_region_min(&self) -> (f32, f32) {
+        unsafe { sys::GetWindowContentRegionMin().into() }
+    }
+    /// Get the maximum of current window's content region, in window coordinates
+    pub fn get_window_content_region_max(&self) -> (f32, f32) {
+        unsafe { sys::GetWindowContentRegionMax().into() }
+    }
+    pub fn get_window_content_region_width(&self) -> f32 {
+        unsafe { sys::GetWindowContentRegionWidth() }
+    }
+    pub fn is_window_collapsed(&self) -> bool { unsafe { sys::IsWindowCollapsed() } }
+}
+
+// Scrolling
+impl<'ui> Ui<'ui> {
+    /// Get current window's horizontal scroll amount, between 0.0 and `get_scroll_max_x`
+    pub fn get_scroll_x(&self) -> f32 { unsafe { sys::GetScrollX() } }
+    /// Get current window's vertical scroll amount, between 0.0 and `get_scroll_max_y`
+    pub fn get_scroll_y(&self) -> f32 { unsafe { sys::GetScrollY() } }
+    pub fn get_scroll_max_x(&self) -> f32 { unsafe { sys::GetScrollMaxX() } }
+    pub fn get_scroll_max_y(&self) -> f32 { unsafe { sys::GetScrollMaxY() } }
+    pub fn set_scroll_x(&self, scroll_x: f32) { unsafe { sys::SetScrollX(scroll_x) } }
+    pub fn set_scroll_y(&self, scroll_y: f32) { unsafe { sys::SetScrollY(scroll_y) } }
+    /// Scrolls the current window so the last item is visible, placing it at `center_y_ratio`
+    /// of the window height: 0.0 for the top, 0.5 for the center and 1.0 for the bottom.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate imgui;
+    /// # use imgui::*;
+    /// fn log_console(ui: &Ui, lines: &[ImString]) {
+    ///     ui.child_frame(im_str!("log"), (0.0, 0.0)).build(|| {
+    ///         let at_bottom = ui.get_scroll_y() >= ui.get_scroll_max_y();
+    ///         for line in lines {
+    ///             ui.text(line);
+    ///         }
+    ///         // Stick to the bottom unless the user scrolled up
+    ///         if at_bottom {
+    ///             ui.set_scroll_here_y(1.0);
+    ///         }
+    ///     });
+    /// }
+    /// # fn main() {
+    /// # }
+    /// ```
+    pub fn set_scroll_here_y(&self, center_y_ratio: f32) {
+        unsafe { sys::SetScrollHereY(center_y_ratio) }
+    }
+    /// Scrolls the current window so `pos_y`, in window coordinates such as those returned by
+    /// `get_cursor_pos`, is placed at `center_y_ratio` of the window height.
+    pub fn set_scroll_from_pos_y(&self, pos_y: f32, center_y_ratio: f32) {
+        unsafe { sys::SetScrollFromPosY(pos_y, center_y_ratio) }
+    }
+    /// Scrolls the current window so the last item is visible, placing it at `center_y_ratio` of
+    /// the window height only if it is out of view. Useful to jump to the selection in a long
+    /// list.
+    pub fn scroll_item_into_view(&self, center_y_ratio: f32) {
+        if !self.is_item_visible() {
+            self.set_scroll_here_y(center_y_ratio);
+        }
+    }
 }
 
 // Layout
@@ -1175,6 +1612,38 @@ impl<'ui> Ui<'ui> {
     pub fn plot_lines<'p>(&self, label: &'p ImStr, values: &'p [f32]) -> PlotLines<'ui, 'p> {
         PlotLines::new(self, label, values)
     }
+    /// Plots values read in place from a field of a slice of structs or from a closure.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate imgui;
+    /// # use imgui::*;
+    /// struct Sample {
+    ///     latency: f32,
+    ///     throughput: f32,
+    /// }
+    ///
+    /// fn telemetry(ui: &Ui, samples: &[Sample], newest: usize) {
+    ///     // Samples are a ring buffer, plotted from the oldest one
+    ///     let latency = PlotValues::field(samples, |s| &s.latency);
+    ///     ui.plot_lines_with_values(im_str!("Latency"), latency)
+    ///         .values_offset(newest + 1)
+    ///         .build();
+    ///     let throughput = |i: usize| samples[i].throughput / 1024.0;
+    ///     let throughput = PlotValues::getter(samples.len(), &throughput);
+    ///     ui.plot_lines_with_values(im_str!("Throughput (KiB/s)"), throughput)
+    ///         .build();
+    /// }
+    /// # fn main() {
+    /// # }
+    /// ```
+    pub fn plot_lines_with_values<'p>(
+        &self,
+        label: &'p ImStr,
+        values: PlotValues<'p>,
+    ) -> PlotLines<'ui, 'p> {
+        PlotLines::with_values(self, label, values)
+    }
 }
 
 impl<'ui> Ui<'ui> {
@@ -1185,6 +1654,26 @@ impl<'ui> Ui<'ui> {
     ) -> PlotHistogram<'ui, 'p> {
         PlotHistogram::new(self, label, values)
     }
+    /// Plots values read in place from a field of a slice of structs or from a closure.
+    pub fn plot_histogram_with_values<'p>(
+        &self,
+        label: &'p ImStr,
+        values: PlotValues<'p>,
+    ) -> PlotHistogram<'ui, 'p> {
+        PlotHistogram::with_values(self, label, values)
+    }
+}
+
+impl<'ui> Ui<'ui> {
+    /// Returns a `Plot` builder for a plot of several series with axes and a legend.
+    pub fn plot<'a, 'p>(&'a self, label: &'p ImStr) -> Plot<'a, 'p> { Plot::new(self, label) }
+}
+
+impl<'ui> Ui<'ui> {
+    /// Returns a `NodeEditor` builder for a canvas of nodes and links.
+    pub fn node_editor<'a, 'p>(&'a self, id: &'p ImStr) -> NodeEditor<'a, 'p> {
+        NodeEditor::new(self, id)
+    }
 }
 
 impl<'ui> Ui<'ui> {
@@ -1266,6 +1755,15 @@ impl<'ui> Ui<'ui> {
     ) -> ChildFrame<'ui, 'p> {
         ChildFrame::new(self, name, size.into())
     }
+    /// Creates a tab bar identified by `id`.
+    pub fn tab_bar<'a, 'p, I: Into<ImId<'p>>>(&'a self, id: I) -> TabBar<'a, 'p> {
+        TabBar::new(self, id)
+    }
+    /// Creates a child window, a scrolling region inside the current window, identified by a
+    /// string or an integer.
+    pub fn child_window<'p, I: Into<ChildWindowId<'p>>>(&self, id: I) -> ChildWindow<'ui, 'p> {
+        ChildWindow::new(self, id)
+    }
 }
 
 impl<'ui> Ui<'ui> {
@@ -1439,14 +1937,145 @@ impl<'ui> Ui<'ui> {
     /// ```
     pub fn is_item_hovered(&self) -> bool { unsafe { sys::IsItemHovered(ImGuiHoveredFlags::None) } }
 
+    /// Returns `true` if the last item is being hovered by the mouse, with `flags` relaxing
+    /// when it counts as hovered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate imgui;
+    /// # use imgui::*;
+    /// fn user_interface(ui: &Ui) {
+    ///     ui.button(im_str!("Drop here"), (0.0, 0.0));
+    ///     let hovered = ui.is_item_hovered_with_flags(
+    ///         ImGuiHoveredFlags::AllowWhenBlockedByPopup
+    ///             | ImGuiHoveredFlags::AllowWhenBlockedByActiveItem,
+    ///     );
+    /// }
+    /// # fn main() {
+    /// # }
+    /// ```
+    pub fn is_item_hovered_with_flags(&self, flags: ImGuiHoveredFlags) -> bool {
+        unsafe { sys::IsItemHovered(flags) }
+    }
+
     /// Return `true` if the current window is being hovered by the mouse.
     pub fn is_window_hovered(&self) -> bool {
         unsafe { sys::IsWindowHovered(ImGuiHoveredFlags::None) }
     }
 
+    /// Returns `true` if the current window is being hovered by the mouse, with `flags`
+    /// extending the test to child windows or relaxing when it counts as hovered.
+    pub fn is_window_hovered_with_flags(&self, flags: ImGuiHoveredFlags) -> bool {
+        unsafe { sys::IsWindowHovered(flags) }
+    }
+
+    /// Returns `true` if the current window is focused.
+    pub fn is_window_focused(&self) -> bool {
+        unsafe { sys::IsWindowFocused(ImGuiFocusedFlags::None) }
+    }
+
+    /// Returns `true` if the current window is focused, with `flags` extending the test to
+    /// child windows, the root window or any window.
+    pub fn is_window_focused_with_flags(&self, flags: ImGuiFocusedFlags) -> bool {
+        unsafe { sys::IsWindowFocused(flags) }
+    }
+
     /// Returns `true` if the last item is being active.
     pub fn is_item_active(&self) -> bool { unsafe { sys::IsItemActive() } }
 
+    /// Returns `true` if the last item is hovered and was clicked with `button`.
+    pub fn is_item_clicked(&self, button: ImMouseButton) -> bool {
+        unsafe { sys::IsItemClicked(button as c_int) }
+    }
+
+    /// Returns `true` if the last item has keyboard or gamepad navigation focus.
+    pub fn is_item_focused(&self) -> bool { unsafe { sys::IsItemFocused() } }
+
+    /// Returns `true` if the last item is visible, i.e. not clipped or scrolled out.
+    pub fn is_item_visible(&self) -> bool { unsafe { sys::IsItemVisible() } }
+
+    pub fn is_any_item_hovered(&self) -> bool { unsafe { sys::IsAnyItemHovered() } }
+
+    pub fn is_any_item_active(&self) -> bool { unsafe { sys::IsAnyItemActive() } }
+
+    pub fn is_any_item_focused(&self) -> bool { unsafe { sys::IsAnyItemFocused() } }
+
+    /// Get previously drawn item's upper-left corner in screen coordinates
+    pub fn get_item_rect_min(&self) -> (f32, f32) { unsafe { sys::GetItemRectMin().into() } }
+
+    /// Get previously drawn item's lower-right corner in screen coordinates
+    pub fn get_item_rect_max(&self) -> (f32, f32) { unsafe { sys::GetItemRectMax().into() } }
+
+    /// Allows the last item to be overlapped by a subsequent item.
+    ///
+    /// Useful for invisible buttons or selectables covering an area with other widgets on top.
+    pub fn set_item_allow_overlap(&self) { unsafe { sys::SetItemAllowOverlap() } }
+
+    /// Focuses the next widget with the keyboard, for example to start typing in a text input
+    /// when a window appears.
+    pub fn set_keyboard_focus_here(&self) { unsafe { sys::SetKeyboardFocusHere(0) } }
+
+    /// Focuses a widget with the keyboard, `offset` widgets after the next one. Use `-1` to
+    /// focus the previous widget.
+    pub fn set_keyboard_focus_here_with_offset(&self, offset: i32) {
+        unsafe { sys::SetKeyboardFocusHere(offset) }
+    }
+
+    /// Makes the last item the default focused item of a newly appearing window.
+    pub fn set_item_default_focus(&self) { unsafe { sys::SetItemDefaultFocus() } }
+
+    /// Builds the UI in `f` greyed out and ignoring the mouse and keyboard if `disabled` is
+    /// `true`.
+    ///
+    /// Widgets built inside can't be hovered, activated or focused. Disabled scopes nest, and
+    /// `disabled(false, ...)` inside a disabled scope leaves its contents disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate imgui;
+    /// # use imgui::*;
+    /// fn user_interface(ui: &Ui, modified: bool) {
+    ///     ui.disabled(!modified, || {
+    ///         if ui.button(im_str!("Save"), (0.0, 0.0)) {
+    ///             // only reachable when modified
+    ///         }
+    ///     });
+    /// }
+    /// # fn main() {
+    /// # }
+    /// ```
+    pub fn disabled<F: FnOnce()>(&self, disabled: bool, f: F) {
+        if !disabled {
+            f();
+            return;
+        }
+        // imgui_internal.h ImGuiItemFlags_Disabled | ImGuiItemFlags_NoNav
+        const ITEM_FLAGS_DISABLED_NO_NAV: c_int = 1 << 2 | 1 << 3;
+        let depth = &self.imgui.disabled_depth;
+        // Only the outermost scope fades, so nested scopes don't compound the alpha
+        let fade = depth.get() == 0;
+        if fade {
+            let alpha = self.imgui.style().Alpha * 0.5;
+            self.push_style_var(StyleVar::Alpha(alpha));
+        }
+        depth.set(depth.get() + 1);
+        unsafe {
+            sys::PushItemFlag(ITEM_FLAGS_DISABLED_NO_NAV, true);
+            sys::PushAllowKeyboardFocus(false);
+        }
+        f();
+        unsafe {
+            sys::PopAllowKeyboardFocus();
+            sys::PopItemFlag();
+        }
+        depth.set(depth.get() - 1);
+        if fade {
+            unsafe { sys::PopStyleVar(1) };
+        }
+    }
+
     /// Group items together as a single item.
     ///
     /// May be useful to handle the same mouse event on a group of items, for example.
@@ -1494,3 +2123,179 @@ impl<'ui> Ui<'ui> {
     /// ```
     pub fn get_window_draw_list(&'ui self) -> WindowDrawList<'ui> { WindowDrawList::new(self) }
 }
+
+// Logging
+impl<'ui> Ui<'ui> {
+    /// Starts logging the text output of the following widgets to stdout.
+    pub fn log_to_tty(&self) { unsafe { sys::LogToTTY(-1) } }
+    /// Starts logging the text output of the following widgets to the file set with
+    /// `ImGui::set_log_filename`.
+    pub fn log_to_file(&self) { unsafe { sys::LogToFile(-1, ptr::null()) } }
+    /// Starts logging the text output of the following widgets to the clipboard.
+    pub fn log_to_clipboard(&self) { unsafe { sys::LogToClipboard(-1) } }
+    /// Stops logging, flushing the output to the clipboard if needed.
+    pub fn log_finish(&self) { unsafe { sys::LogFinish() } }
+    /// Shows buttons for logging to tty, file and clipboard.
+    pub fn log_buttons(&self) { unsafe { sys::LogButtons() } }
+    /// Passes text to the current log without displaying it.
+    pub fn log_text<T: AsRef<str>>(&self, text: T) {
+        let text = ImString::new(text.as_ref());
+        unsafe { sys::LogText(fmt_ptr(), text.as_ptr()) }
+    }
+    /// Runs a function and returns everything its widgets output as text, e.g. the labels of a
+    /// tree or the cells of a table.
+    ///
+    /// The capture goes through the clipboard logger without touching the actual clipboard.
+    /// It must not be nested inside another logging scope.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use imgui::*;
+    /// # let mut imgui = ImGui::init();
+    /// # let ui = imgui.frame((0, 0), (0, 0), 0.1);
+    /// let text = ui.log_to_string(|| {
+    ///     ui.text("Hello");
+    ///     ui.text("World");
+    /// });
+    /// ui.text(text);
+    /// ```
+    pub fn log_to_string<F: FnOnce()>(&self, f: F) -> String {
+        unsafe extern "C" fn capture_text(user_data: *mut c_void, text: *const c_char) {
+            let output = &mut *(user_data as *mut String);
+            output.push_str(&CStr::from_ptr(text).to_string_lossy());
+        }
+
+        let mut output = String::new();
+        unsafe {
+            sys::LogToClipboard(-1);
+            f();
+            let io = &mut *sys::GetIO();
+            let set_clipboard_text_fn = io.SetClipboardTextFn;
+            let clipboard_user_data = io.ClipboardUserData;
+            io.SetClipboardTextFn = Some(capture_text);
+            io.ClipboardUserData = &mut output as *mut String as *mut c_void;
+            sys::LogFinish();
+            io.SetClipboardTextFn = set_clipboard_text_fn;
+            io.ClipboardUserData = clipboard_user_data;
+        }
+        output
+    }
+}
+
+// Keyboard
+impl<'ui> Ui<'ui> {
+    /// Returns `true` if `key` is being held.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use imgui::*;
+    /// fn test(ui: &Ui) {
+    ///     if ui.is_key_pressed(Key::F5) {
+    ///         println!("Refreshing");
+    ///     }
+    ///     if ui.imgui().key_ctrl() && ui.is_key_pressed_no_repeat(Key::S) {
+    ///         println!("Saving");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_key_down(&self, key: Key) -> bool { unsafe { sys::IsKeyDown(key.index() as c_int) } }
+    /// Returns `true` if `key` was pressed this frame, repeating at the configured key repeat
+    /// rate while it is held.
+    pub fn is_key_pressed(&self, key: Key) -> bool {
+        unsafe { sys::IsKeyPressed(key.index() as c_int, true) }
+    }
+    /// Returns `true` if `key` was pressed this frame, ignoring repeats.
+    pub fn is_key_pressed_no_repeat(&self, key: Key) -> bool {
+        unsafe { sys::IsKeyPressed(key.index() as c_int, false) }
+    }
+    /// Returns `true` if `key` was released this frame.
+    pub fn is_key_released(&self, key: Key) -> bool {
+        unsafe { sys::IsKeyReleased(key.index() as c_int) }
+    }
+    /// Returns how many times `key` repeated this frame, using a custom repeat delay and rate
+    /// in seconds. Useful for values stepping faster than the configured key repeat.
+    pub fn key_pressed_amount(&self, key: Key, repeat_delay: f32, rate: f32) -> u32 {
+        unsafe { sys::GetKeyPressedAmount(key.index() as c_int, repeat_delay, rate) as u32 }
+    }
+}
+
+// Mouse
+impl<'ui> Ui<'ui> {
+    /// Returns how far the mouse moved since `button` was pressed, or `(0.0, 0.0)` while the
+    /// drag hasn't passed `io.mouse_drag_threshold`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use imgui::*;
+    /// fn orbit_camera(ui: &Ui, yaw: &mut f32, pitch: &mut f32) {
+    ///     if ui.is_mouse_dragging(ImMouseButton::Right) {
+    ///         let (dx, dy) = ui.mouse_drag_delta(ImMouseButton::Right);
+    ///         *yaw += dx * 0.01;
+    ///         *pitch += dy * 0.01;
+    ///         ui.reset_mouse_drag_delta(ImMouseButton::Right);
+    ///     }
+    /// }
+    /// ```
+    pub fn mouse_drag_delta(&self, button: ImMouseButton) -> (f32, f32) {
+        unsafe { sys::GetMouseDragDelta(button as c_int, -1.0).into() }
+    }
+    /// Returns how far the mouse moved since `button` was pressed, or `(0.0, 0.0)` while the
+    /// drag hasn't passed `lock_threshold` pixels.
+    pub fn mouse_drag_delta_with_threshold(
+        &self,
+        button: ImMouseButton,
+        lock_threshold: f32,
+    ) -> (f32, f32) {
+        unsafe { sys::GetMouseDragDelta(button as c_int, lock_threshold).into() }
+    }
+    pub fn is_mouse_dragging(&self, button: ImMouseButton) -> bool {
+        unsafe { sys::IsMouseDragging(button as c_int, -1.0) }
+    }
+    pub fn is_mouse_dragging_with_threshold(&self, button: ImMouseButton, threshold: f32) -> bool {
+        unsafe { sys::IsMouseDragging(button as c_int, threshold) }
+    }
+    /// Restarts the drag of `button` from the current mouse position, so the next drag delta
+    /// is relative to this frame.
+    pub fn reset_mouse_drag_delta(&self, button: ImMouseButton) {
+        unsafe { sys::ResetMouseDragDelta(button as c_int) }
+    }
+    /// Returns the mouse position at the time the current popup was opened.
+    pub fn mouse_pos_on_opening_current_popup(&self) -> (f32, f32) {
+        unsafe { sys::GetMousePosOnOpeningCurrentPopup().into() }
+    }
+    /// Returns the position where `button` was last clicked.
+    pub fn mouse_clicked_pos(&self, button: ImMouseButton) -> (f32, f32) {
+        self.imgui.io().mouse_clicked_pos(button)
+    }
+    /// Returns how long `button` has been held in seconds, or `None` if it is up.
+    pub fn mouse_down_duration(&self, button: ImMouseButton) -> Option<f32> {
+        let duration = self.imgui.io().mouse_down_duration(button);
+        if duration >= 0.0 {
+            Some(duration)
+        } else {
+            None
+        }
+    }
+    pub fn mouse_wheel(&self) -> f32 { self.imgui.mouse_wheel() }
+    pub fn mouse_wheel_h(&self) -> f32 { self.imgui.mouse_wheel_h() }
+    /// Returns `true` if the mouse is over the given rectangle in screen coordinates, clipped
+    /// by the current clipping rectangle.
+    pub fn is_mouse_hovering_rect<P1, P2>(&self, r_min: P1, r_max: P2) -> bool
+    where
+        P1: Into<ImVec2>,
+        P2: Into<ImVec2>,
+    {
+        unsafe { sys::IsMouseHoveringRect(&r_min.into(), &r_max.into(), true) }
+    }
+    /// Returns `true` if the mouse is over the given rectangle in screen coordinates, ignoring
+    /// the current clipping rectangle.
+    pub fn is_mouse_hovering_rect_unclipped<P1, P2>(&self, r_min: P1, r_max: P2) -> bool
+    where
+        P1: Into<ImVec2>,
+        P2: Into<ImVec2>,
+    {
+        unsafe { sys::IsMouseHoveringRect(&r_min.into(), &r_max.into(), false) }
+    }
+    /// Returns `false` if the backend reported no mouse, for example when it left the window.
+    pub fn is_mouse_pos_valid(&self) -> bool { unsafe { sys::IsMousePosValid(ptr::null()) } }
+}
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 use std::ptr;
 use sys;
 
-use super::{ImStr, Ui};
+use super::{ImStr, Shortcuts, Ui};
 
 #[must_use]
 pub struct Menu<'ui, 'p> {
@@ -57,6 +57,16 @@ impl<'ui, 'p> MenuItem<'ui, 'p> {
         self.shortcut = Some(shortcut);
         self
     }
+    /// Shows the shortcut bound to `action` in `shortcuts`, if any.
+    #[inline]
+    pub fn shortcut_for<A: Copy + PartialEq>(
+        mut self,
+        shortcuts: &'p Shortcuts<A>,
+        action: A,
+    ) -> Self {
+        self.shortcut = shortcuts.label(action);
+        self
+    }
     #[inline]
     pub fn selected(mut self, selected: &'p mut bool) -> Self {
         self.selected = Some(selected);
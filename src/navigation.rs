@@ -0,0 +1,53 @@
+use sys;
+
+/// The state of a gamepad, fed to imgui's navigation inputs with `ImGui::set_gamepad`.
+///
+/// Buttons follow the Xbox controller layout: `a` activates, `b` cancels, `x` opens menus and
+/// `y` enters text input. The shoulder buttons switch windows and, while tweaking a value,
+/// slow it down or speed it up.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct GamepadState {
+    /// Left stick position, each axis in `-1.0..=1.0`, with positive `y` pointing up.
+    pub left_stick: (f32, f32),
+    pub dpad_left: bool,
+    pub dpad_right: bool,
+    pub dpad_up: bool,
+    pub dpad_down: bool,
+    pub a: bool,
+    pub b: bool,
+    pub x: bool,
+    pub y: bool,
+    pub left_shoulder: bool,
+    pub right_shoulder: bool,
+}
+
+impl GamepadState {
+    pub(crate) fn apply(&self, nav_inputs: &mut [f32]) {
+        fn button(pressed: bool) -> f32 {
+            if pressed {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        fn axis(value: f32) -> f32 { value.max(0.0).min(1.0) }
+
+        let mut set = |input: sys::ImGuiNavInput, value: f32| nav_inputs[input.0 as usize] = value;
+        set(sys::ImGuiNavInput::Activate, button(self.a));
+        set(sys::ImGuiNavInput::Cancel, button(self.b));
+        set(sys::ImGuiNavInput::Menu, button(self.x));
+        set(sys::ImGuiNavInput::Input, button(self.y));
+        set(sys::ImGuiNavInput::DpadLeft, button(self.dpad_left));
+        set(sys::ImGuiNavInput::DpadRight, button(self.dpad_right));
+        set(sys::ImGuiNavInput::DpadUp, button(self.dpad_up));
+        set(sys::ImGuiNavInput::DpadDown, button(self.dpad_down));
+        set(sys::ImGuiNavInput::FocusPrev, button(self.left_shoulder));
+        set(sys::ImGuiNavInput::FocusNext, button(self.right_shoulder));
+        set(sys::ImGuiNavInput::TweakSlow, button(self.left_shoulder));
+        set(sys::ImGuiNavInput::TweakFast, button(self.right_shoulder));
+        set(sys::ImGuiNavInput::LStickLeft, axis(-self.left_stick.0));
+        set(sys::ImGuiNavInput::LStickRight, axis(self.left_stick.0));
+        set(sys::ImGuiNavInput::LStickUp, axis(self.left_stick.1));
+        set(sys::ImGuiNavInput::LStickDown, axis(-self.left_stick.1));
+    }
+}
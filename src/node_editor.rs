@@ -0,0 +1,762 @@
+use std::os::raw::c_char;
+use std::{cmp, f32, ptr};
+use sys;
+
+use super::{text_size, ImGuiCol, ImMouseButton, ImStr, ImString, ImVec2, Key, Ui};
+
+// Node metrics at zoom 1.0, in canvas units
+const NODE_PADDING: (f32, f32) = (8.0, 4.0);
+const NODE_ROUNDING: f32 = 4.0;
+const NODE_MIN_WIDTH: f32 = 80.0;
+const PIN_RADIUS: f32 = 4.5;
+const GRID_STEP: f32 = 32.0;
+
+/// An input or output pin of a `Node`.
+///
+/// Pin IDs must be unique in the whole graph, as links refer to pins only.
+#[derive(Clone, Debug)]
+pub struct Pin {
+    id: u32,
+    label: ImString,
+}
+
+impl Pin {
+    pub fn new<T: Into<ImString>>(id: u32, label: T) -> Pin {
+        Pin {
+            id,
+            label: label.into(),
+        }
+    }
+    pub fn id(&self) -> u32 { self.id }
+}
+
+/// A node shown by a `NodeEditor`, with a title and rows of input and output pins.
+///
+/// The node borrows its position from the caller's graph, and the editor moves it in place
+/// when the node is dragged.
+pub struct Node<'a> {
+    id: u32,
+    title: &'a ImStr,
+    pos: &'a mut (f32, f32),
+    inputs: &'a [Pin],
+    outputs: &'a [Pin],
+}
+
+impl<'a> Node<'a> {
+    /// Creates a node at `pos`, in canvas coordinates.
+    pub fn new(id: u32, title: &'a ImStr, pos: &'a mut (f32, f32)) -> Node<'a> {
+        Node {
+            id,
+            title,
+            pos,
+            inputs: &[],
+            outputs: &[],
+        }
+    }
+    #[inline]
+    pub fn inputs(mut self, pins: &'a [Pin]) -> Self {
+        self.inputs = pins;
+        self
+    }
+    #[inline]
+    pub fn outputs(mut self, pins: &'a [Pin]) -> Self {
+        self.outputs = pins;
+        self
+    }
+}
+
+/// A link from an output pin to an input pin.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Link {
+    pub id: u32,
+    pub output: u32,
+    pub input: u32,
+}
+
+/// An edit made in a `NodeEditor`, for the caller to apply to its graph.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum NodeEvent {
+    /// A link was dragged between an output pin and an input pin.
+    LinkCreated { output: u32, input: u32 },
+    /// The selected link was deleted with the Delete key.
+    LinkDeleted(u32),
+    /// A selected node was deleted with the Delete key. Its links are left to the caller.
+    NodeDeleted(u32),
+    /// A node was dropped after being dragged. Its position is already updated.
+    NodeMoved(u32),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Drag {
+    None,
+    Nodes { moved: bool },
+    Link { pin: u32, output: bool },
+    Select { start: (f32, f32) },
+    Pan,
+}
+
+/// The view and selection of a `NodeEditor`, kept by the caller across frames.
+#[derive(Clone, PartialEq, Debug)]
+pub struct NodeEditorState {
+    scroll: (f32, f32),
+    zoom: f32,
+    selected_nodes: Vec<u32>,
+    selected_link: Option<u32>,
+    drag: Drag,
+}
+
+impl NodeEditorState {
+    pub fn new() -> NodeEditorState {
+        NodeEditorState {
+            scroll: (0.0, 0.0),
+            zoom: 1.0,
+            selected_nodes: Vec::new(),
+            selected_link: None,
+            drag: Drag::None,
+        }
+    }
+    /// Returns the offset of the canvas origin from the top-left corner of the editor, in
+    /// pixels.
+    pub fn scroll(&self) -> (f32, f32) { self.scroll }
+    pub fn set_scroll(&mut self, scroll: (f32, f32)) { self.scroll = scroll; }
+    pub fn zoom(&self) -> f32 { self.zoom }
+    /// Sets the zoom factor, clamped between 0.25 and 4.0.
+    pub fn set_zoom(&mut self, zoom: f32) { self.zoom = zoom.max(0.25).min(4.0); }
+    pub fn selected_nodes(&self) -> &[u32] { &self.selected_nodes }
+    pub fn selected_link(&self) -> Option<u32> { self.selected_link }
+    pub fn clear_selection(&mut self) {
+        self.selected_nodes.clear();
+        self.selected_link = None;
+    }
+}
+
+impl Default for NodeEditorState {
+    fn default() -> Self { NodeEditorState::new() }
+}
+
+/// A canvas of nodes connected by links between their pins.
+///
+/// Nodes are moved by dragging them, selected by clicking them (with Ctrl to add to the
+/// selection) or with a selection box dragged on the background. Links are created by
+/// dragging from a pin to a pin of the opposite kind, and selected by clicking them. The
+/// Delete key deletes the selection. The canvas is panned by dragging with the middle mouse
+/// button and zoomed with the mouse wheel.
+///
+/// The editor only reports edits as `NodeEvent`s, except for node positions which it moves
+/// in place.
+///
+/// imgui scrolls the hovered window with the mouse wheel before the editor sees it, so an
+/// editor should be placed in a window built with `scrollable(false)`, or a child frame built
+/// with `show_scrollbar_with_mouse(false)`.
+///
+/// # Example
+/// ```rust,no_run
+/// # #[macro_use] extern crate imgui;
+/// # use imgui::*;
+/// struct GraphNode {
+///     id: u32,
+///     title: ImString,
+///     pos: (f32, f32),
+///     inputs: Vec<Pin>,
+///     outputs: Vec<Pin>,
+/// }
+///
+/// fn graph_editor(
+///     ui: &Ui,
+///     state: &mut NodeEditorState,
+///     graph: &mut Vec<GraphNode>,
+///     links: &mut Vec<Link>,
+///     next_id: &mut u32,
+/// ) {
+///     let events = {
+///         let mut nodes = graph
+///             .iter_mut()
+///             .map(|n| {
+///                 Node::new(n.id, &n.title, &mut n.pos)
+///                     .inputs(&n.inputs)
+///                     .outputs(&n.outputs)
+///             })
+///             .collect::<Vec<_>>();
+///         ui.node_editor(im_str!("graph"))
+///             .build(state, &mut nodes, links)
+///     };
+///     for event in events {
+///         match event {
+///             NodeEvent::LinkCreated { output, input } => {
+///                 *next_id += 1;
+///                 links.push(Link { id: *next_id, output, input });
+///             }
+///             NodeEvent::LinkDeleted(id) => links.retain(|l| l.id != id),
+///             NodeEvent::NodeDeleted(id) => graph.retain(|n| n.id != id),
+///             NodeEvent::NodeMoved(_) => {}
+///         }
+///     }
+/// }
+/// # fn main() {
+/// # }
+/// ```
+#[must_use]
+pub struct NodeEditor<'ui, 'p> {
+    ui: &'ui Ui<'ui>,
+    id: &'p ImStr,
+    size: ImVec2,
+    grid: bool,
+}
+
+impl<'ui, 'p> NodeEditor<'ui, 'p> {
+    pub fn new(ui: &'ui Ui<'ui>, id: &'p ImStr) -> NodeEditor<'ui, 'p> {
+        NodeEditor {
+            ui,
+            id,
+            size: ImVec2::zero(),
+            grid: true,
+        }
+    }
+    /// Sets the size of the editor. A component of 0.0 uses the available space, and a
+    /// negative one the available space minus its absolute value.
+    #[inline]
+    pub fn size<S: Into<ImVec2>>(mut self, size: S) -> Self {
+        self.size = size.into();
+        self
+    }
+    #[inline]
+    pub fn grid(mut self, value: bool) -> Self {
+        self.grid = value;
+        self
+    }
+    pub fn build(
+        self,
+        state: &mut NodeEditorState,
+        nodes: &mut [Node],
+        links: &[Link],
+    ) -> Vec<NodeEvent> {
+        let NodeEditor { ui, id, size, grid } = self;
+        let mut events = Vec::new();
+        ui.push_id(id.to_str());
+        let (x, y) = ui.get_cursor_screen_pos();
+        let (avail_width, avail_height) = ui.get_content_region_avail();
+        let fit = |size: f32, avail: f32| {
+            if size > 0.0 {
+                size
+            } else {
+                (avail + size).max(1.0)
+            }
+        };
+        let canvas_size = (fit(size.x, avail_width), fit(size.y, avail_height));
+        let canvas_id = unsafe { ImStr::from_utf8_with_nul_unchecked(b"##canvas\0") };
+        ui.invisible_button(canvas_id, canvas_size);
+        let hovered = ui.is_item_hovered();
+        let canvas_max = (x + canvas_size.0, y + canvas_size.1);
+
+        let imgui = ui.imgui();
+        let mouse_pos = imgui.mouse_pos();
+        let (mouse_dx, mouse_dy) = imgui.mouse_delta();
+
+        // Panning and zooming
+        if hovered && state.drag == Drag::None && imgui.is_mouse_clicked(ImMouseButton::Middle) {
+            state.drag = Drag::Pan;
+        }
+        if state.drag == Drag::Pan {
+            if imgui.is_mouse_down(ImMouseButton::Middle) {
+                state.scroll = (state.scroll.0 + mouse_dx, state.scroll.1 + mouse_dy);
+            } else {
+                state.drag = Drag::None;
+            }
+        }
+        let wheel = imgui.mouse_wheel();
+        if hovered && wheel != 0.0 {
+            let old_zoom = state.zoom;
+            state.set_zoom(old_zoom * 1.1f32.powf(wheel));
+            // Keep the canvas point under the mouse in place
+            let ratio = state.zoom / old_zoom;
+            let (ox, oy) = (x + state.scroll.0, y + state.scroll.1);
+            let origin = (
+                mouse_pos.0 - (mouse_pos.0 - ox) * ratio,
+                mouse_pos.1 - (mouse_pos.1 - oy) * ratio,
+            );
+            state.scroll = (origin.0 - x, origin.1 - y);
+        }
+
+        let selected =
+            |state: &NodeEditorState, node: &Node| state.selected_nodes.contains(&node.id);
+        // Drawing order, so the topmost node is the last one
+        let order = {
+            let (mut selected_order, mut order): (Vec<usize>, Vec<usize>) =
+                (0..nodes.len()).partition(|&i| selected(state, &nodes[i]));
+            order.append(&mut selected_order);
+            order
+        };
+
+        // Clicks, hit-testing the topmost pin, node or link
+        let mut layouts = layout_nodes(nodes, state, (x, y));
+        if hovered && state.drag == Drag::None && imgui.is_mouse_clicked(ImMouseButton::Left) {
+            let ctrl = imgui.key_ctrl();
+            let pin = order
+                .iter()
+                .rev()
+                .filter_map(|&i| layouts[i].pin_at(mouse_pos, state.zoom))
+                .next();
+            let node = order
+                .iter()
+                .rev()
+                .cloned()
+                .find(|&i| contains(layouts[i].rect, mouse_pos));
+            if let Some(pin) = pin {
+                state.drag = Drag::Link {
+                    pin: pin.id,
+                    output: pin.output,
+                };
+            } else if let Some(index) = node {
+                let id = nodes[index].id;
+                state.selected_link = None;
+                if ctrl {
+                    if state.selected_nodes.contains(&id) {
+                        state.selected_nodes.retain(|&n| n != id);
+                    } else {
+                        state.selected_nodes.push(id);
+                    }
+                } else if !state.selected_nodes.contains(&id) {
+                    state.selected_nodes = vec![id];
+                }
+                if state.selected_nodes.contains(&id) {
+                    state.drag = Drag::Nodes { moved: false };
+                }
+            } else if let Some(link) = link_at(links, &layouts, mouse_pos, state.zoom) {
+                if !ctrl {
+                    state.selected_nodes.clear();
+                }
+                state.selected_link = Some(link);
+            } else {
+                if !ctrl {
+                    state.clear_selection();
+                }
+                state.drag = Drag::Select { start: mouse_pos };
+            }
+        }
+
+        // Dragging
+        let released = !imgui.is_mouse_down(ImMouseButton::Left);
+        match state.drag {
+            Drag::Nodes { moved } => {
+                if released {
+                    if moved {
+                        for &id in &state.selected_nodes {
+                            events.push(NodeEvent::NodeMoved(id));
+                        }
+                    }
+                    state.drag = Drag::None;
+                } else if mouse_dx != 0.0 || mouse_dy != 0.0 {
+                    let delta = (mouse_dx / state.zoom, mouse_dy / state.zoom);
+                    for node in nodes.iter_mut() {
+                        if state.selected_nodes.contains(&node.id) {
+                            node.pos.0 += delta.0;
+                            node.pos.1 += delta.1;
+                        }
+                    }
+                    state.drag = Drag::Nodes { moved: true };
+                    layouts = layout_nodes(nodes, state, (x, y));
+                }
+            }
+            Drag::Link { pin, output } if released => {
+                let target = layouts
+                    .iter()
+                    .filter_map(|layout| layout.pin_at(mouse_pos, state.zoom))
+                    .find(|target| target.output != output);
+                if let Some(target) = target {
+                    events.push(if output {
+                        NodeEvent::LinkCreated {
+                            output: pin,
+                            input: target.id,
+                        }
+                    } else {
+                        NodeEvent::LinkCreated {
+                            output: target.id,
+                            input: pin,
+                        }
+                    });
+                }
+                state.drag = Drag::None;
+            }
+            Drag::Select { start } if released => {
+                let rect = normalize(start, mouse_pos);
+                for (node, layout) in nodes.iter().zip(&layouts) {
+                    if intersects(rect, layout.rect) && !state.selected_nodes.contains(&node.id) {
+                        state.selected_nodes.push(node.id);
+                    }
+                }
+                state.drag = Drag::None;
+            }
+            _ => (),
+        }
+
+        // Deleting
+        if ui.is_window_focused() && !imgui.want_text_input() && ui.is_key_pressed(Key::Delete) {
+            if let Some(link) = state.selected_link {
+                events.push(NodeEvent::LinkDeleted(link));
+            }
+            for &node in &state.selected_nodes {
+                events.push(NodeEvent::NodeDeleted(node));
+            }
+            state.clear_selection();
+        }
+
+        {
+            let color = |col: ImGuiCol, alpha: f32| unsafe { sys::GetColorU32(col, alpha) };
+            let zoom = state.zoom;
+            let font_size = unsafe { sys::GetFontSize() } * zoom;
+            let draw_list = ui.get_window_draw_list();
+            draw_list.with_clip_rect_intersect((x, y), canvas_max, || {
+                draw_list
+                    .add_rect((x, y), canvas_max, color(ImGuiCol::FrameBg, 1.0))
+                    .filled(true)
+                    .build();
+                if grid {
+                    let step = GRID_STEP * zoom;
+                    let grid_color = color(ImGuiCol::Border, 0.4);
+                    let mut gx = x + (state.scroll.0 % step + step) % step;
+                    while gx < canvas_max.0 {
+                        draw_list
+                            .add_line((gx, y), (gx, canvas_max.1), grid_color)
+                            .build();
+                        gx += step;
+                    }
+                    let mut gy = y + (state.scroll.1 % step + step) % step;
+                    while gy < canvas_max.1 {
+                        draw_list
+                            .add_line((x, gy), (canvas_max.0, gy), grid_color)
+                            .build();
+                        gy += step;
+                    }
+                }
+
+                for link in links {
+                    if let Some((from, to)) = link_ends(link, &layouts) {
+                        let (cp0, cp1) = link_controls(from, to, zoom);
+                        let link_color = if state.selected_link == Some(link.id) {
+                            color(ImGuiCol::ButtonActive, 1.0)
+                        } else {
+                            color(ImGuiCol::Text, 0.6)
+                        };
+                        draw_link(&draw_list, from, cp0, cp1, to, link_color, zoom);
+                    }
+                }
+
+                let hovered_pin = if hovered {
+                    order
+                        .iter()
+                        .rev()
+                        .filter_map(|&i| layouts[i].pin_at(mouse_pos, zoom))
+                        .next()
+                        .map(|pin| pin.id)
+                } else {
+                    None
+                };
+                // Selected nodes are drawn on top, in the second channel
+                draw_list.channels_split(2, |channels| {
+                    for (node, layout) in nodes.iter().zip(&layouts) {
+                        let is_selected = selected(state, node);
+                        channels.set_current(is_selected as u32);
+                        let ((left, top), (right, bottom)) = layout.rect;
+                        let rounding = NODE_ROUNDING * zoom;
+                        draw_list
+                            .add_rect((left, top), (right, bottom), color(ImGuiCol::PopupBg, 1.0))
+                            .filled(true)
+                            .rounding(rounding)
+                            .build();
+                        let title_color = if is_selected {
+                            color(ImGuiCol::HeaderActive, 1.0)
+                        } else {
+                            color(ImGuiCol::Header, 1.0)
+                        };
+                        draw_list
+                            .add_rect((left, top), (right, top + layout.title_height), title_color)
+                            .filled(true)
+                            .rounding(rounding)
+                            .round_bot_left(false)
+                            .round_bot_right(false)
+                            .build();
+                        let (border_color, thickness) = if is_selected {
+                            (color(ImGuiCol::HeaderActive, 1.0), 2.0)
+                        } else {
+                            (color(ImGuiCol::Border, 1.0), 1.0)
+                        };
+                        draw_list
+                            .add_rect((left, top), (right, bottom), border_color)
+                            .rounding(rounding)
+                            .thickness(thickness)
+                            .build();
+                        let text_color = color(ImGuiCol::Text, 1.0);
+                        let padding = (NODE_PADDING.0 * zoom, NODE_PADDING.1 * zoom);
+                        add_text(
+                            (left + padding.0, top + padding.1),
+                            text_color,
+                            font_size,
+                            visible_label(node.title),
+                        );
+                        for pin in &layout.pins {
+                            let linked = links
+                                .iter()
+                                .any(|l| l.output == pin.id || l.input == pin.id);
+                            let pin_color = if hovered_pin == Some(pin.id) {
+                                color(ImGuiCol::ButtonHovered, 1.0)
+                            } else {
+                                text_color
+                            };
+                            draw_list
+                                .add_circle(pin.pos, PIN_RADIUS * zoom, pin_color)
+                                .filled(linked)
+                                .num_segments(12)
+                                .build();
+                            let label_width = text_size(pin.label.to_str()).x * zoom;
+                            let label_x = if pin.output {
+                                pin.pos.0 - padding.0 - label_width
+                            } else {
+                                pin.pos.0 + padding.0
+                            };
+                            let label_pos = (label_x, pin.pos.1 - font_size * 0.5);
+                            add_text(label_pos, text_color, font_size, pin.label.to_str());
+                        }
+                    }
+                });
+
+                match state.drag {
+                    Drag::Link { pin, output } => {
+                        let start = layouts
+                            .iter()
+                            .flat_map(|layout| layout.pins.iter())
+                            .find(|p| p.id == pin)
+                            .map(|p| p.pos);
+                        if let Some(start) = start {
+                            let (from, to) = if output {
+                                (start, mouse_pos)
+                            } else {
+                                (mouse_pos, start)
+                            };
+                            let (cp0, cp1) = link_controls(from, to, zoom);
+                            let link_color = color(ImGuiCol::ButtonActive, 1.0);
+                            draw_link(&draw_list, from, cp0, cp1, to, link_color, zoom);
+                        }
+                    }
+                    Drag::Select { start } => {
+                        let (min, max) = normalize(start, mouse_pos);
+                        draw_list
+                            .add_rect(min, max, color(ImGuiCol::TextSelectedBg, 1.0))
+                            .filled(true)
+                            .build();
+                        draw_list
+                            .add_rect(min, max, color(ImGuiCol::HeaderActive, 1.0))
+                            .build();
+                    }
+                    _ => (),
+                }
+            });
+        }
+        ui.pop_id();
+        events
+    }
+}
+
+type Rect = ((f32, f32), (f32, f32));
+
+struct PinLayout<'a> {
+    id: u32,
+    label: &'a ImStr,
+    output: bool,
+    pos: (f32, f32),
+}
+
+struct NodeLayout<'a> {
+    rect: Rect,
+    title_height: f32,
+    pins: Vec<PinLayout<'a>>,
+}
+
+impl<'a> NodeLayout<'a> {
+    fn pin_at(&self, pos: (f32, f32), zoom: f32) -> Option<&PinLayout<'a>> {
+        // Pins are small, so they can be grabbed a little outside of their circle
+        let radius = PIN_RADIUS * zoom + 3.0;
+        self.pins.iter().find(|pin| {
+            let (dx, dy) = (pin.pos.0 - pos.0, pin.pos.1 - pos.1);
+            dx * dx + dy * dy <= radius * radius
+        })
+    }
+}
+
+// Screen rectangles of the nodes and positions of their pins
+fn layout_nodes<'a>(
+    nodes: &[Node<'a>],
+    state: &NodeEditorState,
+    canvas_pos: (f32, f32),
+) -> Vec<NodeLayout<'a>> {
+    let zoom = state.zoom;
+    let origin = (canvas_pos.0 + state.scroll.0, canvas_pos.1 + state.scroll.1);
+    let font_size = unsafe { sys::GetFontSize() };
+    let row_height = font_size + NODE_PADDING.1;
+    let label_width = |pins: &[Pin]| {
+        pins.iter()
+            .map(|pin| text_size(pin.label.to_str()).x)
+            .fold(0.0, |a: f32, b| a.max(b))
+    };
+    nodes
+        .iter()
+        .map(|node| {
+            let title_width = text_size(visible_label(node.title)).x;
+            let pins_width =
+                label_width(node.inputs) + label_width(node.outputs) + 4.0 * PIN_RADIUS;
+            let width = (title_width.max(pins_width) + NODE_PADDING.0 * 2.0).max(NODE_MIN_WIDTH);
+            let title_height = font_size + NODE_PADDING.1 * 2.0;
+            let rows = cmp::max(node.inputs.len(), node.outputs.len()) as f32;
+            let height = title_height + rows * row_height + NODE_PADDING.1;
+            let min = (origin.0 + node.pos.0 * zoom, origin.1 + node.pos.1 * zoom);
+            let max = (min.0 + width * zoom, min.1 + height * zoom);
+            let pin_y = |row: usize| {
+                min.1
+                    + (title_height + NODE_PADDING.1 * 0.5 + (row as f32 + 0.5) * row_height) * zoom
+            };
+            let inputs = node.inputs.iter().enumerate().map(|(row, pin)| PinLayout {
+                id: pin.id,
+                label: &pin.label,
+                output: false,
+                pos: (min.0, pin_y(row)),
+            });
+            let outputs = node.outputs.iter().enumerate().map(|(row, pin)| PinLayout {
+                id: pin.id,
+                label: &pin.label,
+                output: true,
+                pos: (max.0, pin_y(row)),
+            });
+            NodeLayout {
+                rect: (min, max),
+                title_height: title_height * zoom,
+                pins: inputs.chain(outputs).collect(),
+            }
+        })
+        .collect()
+}
+
+fn link_ends(link: &Link, layouts: &[NodeLayout]) -> Option<((f32, f32), (f32, f32))> {
+    let pin_pos = |id: u32, output: bool| {
+        layouts
+            .iter()
+            .flat_map(|layout| layout.pins.iter())
+            .find(|pin| pin.id == id && pin.output == output)
+            .map(|pin| pin.pos)
+    };
+    match (pin_pos(link.output, true), pin_pos(link.input, false)) {
+        (Some(from), Some(to)) => Some((from, to)),
+        _ => None,
+    }
+}
+
+// Control points making links leave outputs and enter inputs horizontally
+fn link_controls(from: (f32, f32), to: (f32, f32), zoom: f32) -> ((f32, f32), (f32, f32)) {
+    let dx = ((to.0 - from.0).abs() * 0.5).max(40.0 * zoom);
+    ((from.0 + dx, from.1), (to.0 - dx, to.1))
+}
+
+fn draw_link(
+    draw_list: &super::WindowDrawList,
+    from: (f32, f32),
+    cp0: (f32, f32),
+    cp1: (f32, f32),
+    to: (f32, f32),
+    color: sys::ImU32,
+    zoom: f32,
+) {
+    draw_list
+        .add_bezier_curve(from, cp0, cp1, to, color)
+        .thickness(2.0 * zoom)
+        .num_segments(24)
+        .build();
+}
+
+fn link_at(links: &[Link], layouts: &[NodeLayout], pos: (f32, f32), zoom: f32) -> Option<u32> {
+    const SEGMENTS: usize = 24;
+    let max_distance = 4.0 + zoom;
+    links
+        .iter()
+        .filter_map(|link| link_ends(link, layouts).map(|ends| (link, ends)))
+        .map(|(link, (from, to))| {
+            let (cp0, cp1) = link_controls(from, to, zoom);
+            let points = (0..SEGMENTS + 1)
+                .map(|i| bezier_point(from, cp0, cp1, to, i as f32 / SEGMENTS as f32))
+                .collect::<Vec<_>>();
+            let distance = points
+                .windows(2)
+                .map(|segment| segment_distance(segment[0], segment[1], pos))
+                .fold(f32::MAX, |a, b| a.min(b));
+            (link.id, distance)
+        })
+        .filter(|&(_, distance)| distance <= max_distance)
+        .fold(
+            None,
+            |nearest: Option<(u32, f32)>, (id, distance)| match nearest {
+                Some((_, nearest_distance)) if nearest_distance <= distance => nearest,
+                _ => Some((id, distance)),
+            },
+        )
+        .map(|(id, _)| id)
+}
+
+fn bezier_point(
+    p0: (f32, f32),
+    p1: (f32, f32),
+    p2: (f32, f32),
+    p3: (f32, f32),
+    t: f32,
+) -> (f32, f32) {
+    let u = 1.0 - t;
+    let (w0, w1, w2, w3) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+    (
+        w0 * p0.0 + w1 * p1.0 + w2 * p2.0 + w3 * p3.0,
+        w0 * p0.1 + w1 * p1.1 + w2 * p2.1 + w3 * p3.1,
+    )
+}
+
+fn segment_distance(a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> f32 {
+    let (abx, aby) = (b.0 - a.0, b.1 - a.1);
+    let length_squared = abx * abx + aby * aby;
+    let t = if length_squared > 0.0 {
+        (((p.0 - a.0) * abx + (p.1 - a.1) * aby) / length_squared)
+            .max(0.0)
+            .min(1.0)
+    } else {
+        0.0
+    };
+    let (dx, dy) = (a.0 + abx * t - p.0, a.1 + aby * t - p.1);
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn contains(((left, top), (right, bottom)): Rect, (x, y): (f32, f32)) -> bool {
+    x >= left && x < right && y >= top && y < bottom
+}
+
+fn intersects(a: Rect, b: Rect) -> bool {
+    (a.0).0 < (b.1).0 && (b.0).0 < (a.1).0 && (a.0).1 < (b.1).1 && (b.0).1 < (a.1).1
+}
+
+fn normalize(a: (f32, f32), b: (f32, f32)) -> Rect {
+    ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)))
+}
+
+fn visible_label(label: &ImStr) -> &str { label.to_str().split("##").next().unwrap_or("") }
+
+// Draws text with the current font at any size, to follow the zoom
+fn add_text(pos: (f32, f32), color: sys::ImU32, font_size: f32, text: &str) {
+    let pos: ImVec2 = pos.into();
+    unsafe {
+        let start = text.as_ptr() as *const c_char;
+        let end = start.offset(text.len() as isize);
+        sys::ImDrawList_AddText1(
+            sys::GetWindowDrawList(),
+            sys::GetFont(),
+            font_size,
+            &pos,
+            color,
+            start,
+            end,
+            0.0,
+            ptr::null(),
+        );
+    }
+}
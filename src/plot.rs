@@ -0,0 +1,638 @@
+use std::cmp;
+use std::f32;
+use std::os::raw::{c_char, c_int};
+use sys;
+use sys::{ImGuiID, ImGuiStorage};
+
+use super::{text_size, ImColor, ImGuiCol, ImMouseButton, ImStr, ImVec2, Ui};
+
+// Colors of series without an explicit color, in order
+static PALETTE: [[f32; 3]; 8] = [
+    [0.00, 0.75, 1.00],
+    [1.00, 0.50, 0.00],
+    [0.30, 0.85, 0.30],
+    [1.00, 0.30, 0.30],
+    [0.70, 0.50, 1.00],
+    [1.00, 0.85, 0.20],
+    [0.40, 0.90, 0.90],
+    [1.00, 0.50, 0.80],
+];
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum SeriesKind {
+    Line,
+    Scatter,
+    Bars,
+}
+
+/// A series of values drawn by a `Plot`.
+///
+/// Values are plotted against their index, unless x coordinates are given with `xs`.
+#[derive(Copy, Clone, Debug)]
+pub struct PlotSeries<'p> {
+    label: &'p ImStr,
+    kind: SeriesKind,
+    xs: Option<&'p [f32]>,
+    ys: &'p [f32],
+    color: Option<ImColor>,
+    bar_width: f32,
+}
+
+impl<'p> PlotSeries<'p> {
+    fn new(label: &'p ImStr, kind: SeriesKind, ys: &'p [f32]) -> PlotSeries<'p> {
+        PlotSeries {
+            label,
+            kind,
+            xs: None,
+            ys,
+            color: None,
+            bar_width: 0.67,
+        }
+    }
+    /// Values joined by lines.
+    pub fn line(label: &'p ImStr, ys: &'p [f32]) -> PlotSeries<'p> {
+        PlotSeries::new(label, SeriesKind::Line, ys)
+    }
+    /// Values drawn as dots.
+    pub fn scatter(label: &'p ImStr, ys: &'p [f32]) -> PlotSeries<'p> {
+        PlotSeries::new(label, SeriesKind::Scatter, ys)
+    }
+    /// Values drawn as bars from zero.
+    pub fn bars(label: &'p ImStr, ys: &'p [f32]) -> PlotSeries<'p> {
+        PlotSeries::new(label, SeriesKind::Bars, ys)
+    }
+    /// Sets the x coordinates of the values. Values without a matching x are not drawn.
+    #[inline]
+    pub fn xs(mut self, xs: &'p [f32]) -> Self {
+        self.xs = Some(xs);
+        self
+    }
+    #[inline]
+    pub fn color<C: Into<ImColor>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+    /// Sets the width of bars, in x units. Defaults to 0.67.
+    #[inline]
+    pub fn bar_width(mut self, width: f32) -> Self {
+        self.bar_width = width;
+        self
+    }
+    fn len(&self) -> usize { cmp::min(self.xs.map(|xs| xs.len()).unwrap_or(!0), self.ys.len()) }
+    fn point(&self, index: usize) -> (f32, f32) {
+        let x = self.xs.map(|xs| xs[index]).unwrap_or(index as f32);
+        (x, self.ys[index])
+    }
+}
+
+/// A plot of several line, scatter and bar series, with axes, a grid and a legend.
+///
+/// Axes fit the data unless ranges are set with `x_range` and `y_range`. The view can be
+/// zoomed with the mouse wheel and panned by dragging, and double-clicking the plot goes back
+/// to the default view. Hovering the plot shows the nearest value, and clicking a series in
+/// the legend hides it. The view and hidden series are kept in imgui's state storage under
+/// the plot label.
+///
+/// imgui scrolls the hovered window with the mouse wheel before the plot sees it, so a plot
+/// zoomed with the wheel should be placed in a window built with `scrollable(false)`, or a
+/// child frame built with `show_scrollbar_with_mouse(false)`.
+///
+/// # Example
+/// ```rust,no_run
+/// # #[macro_use] extern crate imgui;
+/// # use imgui::*;
+/// # fn main() {
+/// # let mut imgui = ImGui::init();
+/// # let ui = imgui.frame((0, 0), (0, 0), 0.1);
+/// let times = [0.0, 0.5, 1.0, 1.5, 2.0];
+/// let latency = [12.0, 15.5, 11.0, 30.2, 14.1];
+/// let errors = [0.0, 1.0, 0.0, 3.0, 0.0];
+/// ui.plot(im_str!("Telemetry"))
+///     .size((0.0, 200.0))
+///     .series(PlotSeries::line(im_str!("latency (ms)"), &latency).xs(&times))
+///     .series(
+///         PlotSeries::bars(im_str!("errors"), &errors)
+///             .xs(&times)
+///             .bar_width(0.25)
+///             .color([1.0, 0.3, 0.3]),
+///     )
+///     .build();
+/// # }
+/// ```
+#[must_use]
+pub struct Plot<'ui, 'p> {
+    ui: &'ui Ui<'ui>,
+    label: &'p ImStr,
+    size: ImVec2,
+    x_range: Option<(f32, f32)>,
+    y_range: Option<(f32, f32)>,
+    legend: bool,
+    series: Vec<PlotSeries<'p>>,
+}
+
+impl<'ui, 'p> Plot<'ui, 'p> {
+    pub fn new(ui: &'ui Ui<'ui>, label: &'p ImStr) -> Plot<'ui, 'p> {
+        Plot {
+            ui,
+            label,
+            size: ImVec2::zero(),
+            x_range: None,
+            y_range: None,
+            legend: true,
+            series: Vec::new(),
+        }
+    }
+    /// Sets the size of the plot. A width of 0.0 uses the available width, and a negative
+    /// one the available width minus its absolute value. A height of 0.0 defaults to 150.0.
+    #[inline]
+    pub fn size<S: Into<ImVec2>>(mut self, size: S) -> Self {
+        self.size = size.into();
+        self
+    }
+    /// Sets the default x axis range instead of fitting the data.
+    #[inline]
+    pub fn x_range(mut self, min: f32, max: f32) -> Self {
+        self.x_range = Some((min, max));
+        self
+    }
+    /// Sets the default y axis range instead of fitting the data.
+    #[inline]
+    pub fn y_range(mut self, min: f32, max: f32) -> Self {
+        self.y_range = Some((min, max));
+        self
+    }
+    #[inline]
+    pub fn legend(mut self, value: bool) -> Self {
+        self.legend = value;
+        self
+    }
+    #[inline]
+    pub fn series(mut self, series: PlotSeries<'p>) -> Self {
+        self.series.push(series);
+        self
+    }
+    pub fn build(self) {
+        let Plot {
+            ui,
+            label,
+            size,
+            x_range,
+            y_range,
+            legend,
+            series,
+        } = self;
+        ui.push_id(label.to_str());
+        let storage = unsafe { sys::GetStateStorage() };
+        let colors = series
+            .iter()
+            .enumerate()
+            .map(|(index, s)| s.color.unwrap_or(PALETTE[index % PALETTE.len()].into()))
+            .collect::<Vec<_>>();
+        let hidden_keys = series
+            .iter()
+            .map(|s| unsafe { sys::GetID(s.label.as_ptr()) })
+            .collect::<Vec<_>>();
+        let visible = hidden_keys
+            .iter()
+            .map(|&key| unsafe { sys::ImGuiStorage_GetInt(storage, key, 0) == 0 })
+            .collect::<Vec<_>>();
+
+        let (frame_padding, frame_rounding) = {
+            let style = ui.imgui().style();
+            (style.FramePadding, style.FrameRounding)
+        };
+        let line_height = unsafe { sys::GetTextLineHeight() };
+        let (x, y) = ui.get_cursor_screen_pos();
+        let (avail_width, _) = ui.get_content_region_avail();
+        let width = if size.x > 0.0 {
+            size.x
+        } else {
+            (avail_width + size.x).max(1.0)
+        };
+        let height = if size.y > 0.0 { size.y } else { 150.0 };
+        let plot_id = unsafe { ImStr::from_utf8_with_nul_unchecked(b"##plot\0") };
+        ui.invisible_button(plot_id, (width, height));
+        let hovered = ui.is_item_hovered();
+        let active = ui.is_item_active();
+        ui.set_item_allow_overlap();
+
+        let default_view = || {
+            let fitted = fit(&series, &visible);
+            View {
+                x: x_range.unwrap_or(fitted.x),
+                y: y_range.unwrap_or(fitted.y),
+            }
+        };
+        let mut view = View::load(storage).unwrap_or_else(&default_view);
+        let title = label.to_str().split("##").next().unwrap_or("");
+        let title_height = if title.is_empty() {
+            0.0
+        } else {
+            line_height + frame_padding.y
+        };
+        let area_top = y + frame_padding.y + title_height;
+        let area_bottom = y + height - frame_padding.y * 2.0 - line_height;
+        let y_ticks = ticks(view.y, (area_bottom - area_top) / (line_height * 2.5));
+        let y_label_width = y_ticks
+            .iter()
+            .map(|&(_, ref text)| text_size(text).x)
+            .fold(0.0, |a: f32, b| a.max(b));
+        let area = (
+            (x + frame_padding.x * 2.0 + y_label_width, area_top),
+            (x + width - frame_padding.x, area_bottom),
+        );
+        let ((left, top), (right, bottom)) = area;
+        let area_size = ((right - left).max(1.0), (bottom - top).max(1.0));
+
+        let mouse_pos = ui.imgui().mouse_pos();
+        let in_area = mouse_pos.0 >= left
+            && mouse_pos.0 < right
+            && mouse_pos.1 >= top
+            && mouse_pos.1 < bottom;
+        if hovered && ui.imgui().is_mouse_double_clicked(ImMouseButton::Left) {
+            View::reset(storage);
+            view = default_view();
+        } else {
+            let wheel = ui.imgui().mouse_wheel();
+            if hovered && in_area && wheel != 0.0 {
+                let factor = 0.9f32.powf(wheel);
+                let (mx, my) = view.to_plot(area, mouse_pos);
+                view.x = zoom(view.x, mx, factor);
+                view.y = zoom(view.y, my, factor);
+                view.store(storage);
+            }
+            if active && ui.is_mouse_dragging(ImMouseButton::Left) {
+                let (dx, dy) = ui.imgui().mouse_delta();
+                let dx = -dx / area_size.0 * (view.x.1 - view.x.0);
+                let dy = dy / area_size.1 * (view.y.1 - view.y.0);
+                view.x = (view.x.0 + dx, view.x.1 + dx);
+                view.y = (view.y.0 + dy, view.y.1 + dy);
+                view.store(storage);
+            }
+        }
+        let x_ticks = ticks(view.x, area_size.0 / 80.0);
+
+        // Legend entries toggle their series when clicked
+        let legend_entries = if legend && !series.is_empty() {
+            let text_width = series
+                .iter()
+                .map(|s| ui.calc_text_size(s.label, true, -1.0).x)
+                .fold(0.0, |a: f32, b| a.max(b));
+            let entry_size = (line_height * 1.5 + text_width, line_height);
+            let origin = (
+                right - entry_size.0 - frame_padding.x * 2.0,
+                top + frame_padding.y,
+            );
+            let legend_id = unsafe { ImStr::from_utf8_with_nul_unchecked(b"##legend\0") };
+            let entries = (0..series.len())
+                .map(|index| {
+                    let pos = (
+                        origin.0 + frame_padding.x,
+                        origin.1 + frame_padding.y + entry_size.1 * index as f32,
+                    );
+                    ui.set_cursor_screen_pos(pos);
+                    ui.push_id(index as i32);
+                    if ui.invisible_button(legend_id, entry_size) {
+                        unsafe {
+                            sys::ImGuiStorage_SetInt(
+                                storage,
+                                hidden_keys[index],
+                                visible[index] as c_int,
+                            )
+                        };
+                    }
+                    ui.pop_id();
+                    pos
+                })
+                .collect::<Vec<_>>();
+            let legend_size = (
+                entry_size.0 + frame_padding.x * 2.0,
+                entry_size.1 * series.len() as f32 + frame_padding.y * 2.0,
+            );
+            Some((origin, legend_size, entries))
+        } else {
+            None
+        };
+
+        let hovered_point = if hovered && in_area && !active {
+            nearest(&series, &visible, |p| view.to_screen(area, p), mouse_pos)
+        } else {
+            None
+        };
+
+        {
+            let color = |col: ImGuiCol, alpha: f32| unsafe { sys::GetColorU32(col, alpha) };
+            let text_color = color(ImGuiCol::Text, 1.0);
+            let grid_color = color(ImGuiCol::Border, 0.5);
+            let draw_list = ui.get_window_draw_list();
+            draw_list
+                .add_rect(
+                    (x, y),
+                    (x + width, y + height),
+                    color(ImGuiCol::FrameBg, 1.0),
+                )
+                .filled(true)
+                .rounding(frame_rounding)
+                .build();
+            if !title.is_empty() {
+                let title_x = x + (width - text_size(title).x) * 0.5;
+                draw_list.add_text((title_x, y + frame_padding.y), text_color, title);
+            }
+            for &(value, ref text) in &x_ticks {
+                let (sx, _) = view.to_screen(area, (value, 0.0));
+                draw_list
+                    .add_line((sx, top), (sx, bottom), grid_color)
+                    .build();
+                let text_x = sx - text_size(text).x * 0.5;
+                draw_list.add_text((text_x, bottom + frame_padding.y), text_color, text);
+            }
+            for &(value, ref text) in &y_ticks {
+                let (_, sy) = view.to_screen(area, (0.0, value));
+                draw_list
+                    .add_line((left, sy), (right, sy), grid_color)
+                    .build();
+                let text_x = left - frame_padding.x - text_size(text).x;
+                draw_list.add_text((text_x, sy - line_height * 0.5), text_color, text);
+            }
+            draw_list
+                .add_rect((left, top), (right, bottom), color(ImGuiCol::Border, 1.0))
+                .build();
+
+            draw_list.with_clip_rect_intersect((left, top), (right, bottom), || {
+                for (index, s) in series.iter().enumerate() {
+                    if !visible[index] {
+                        continue;
+                    }
+                    let series_color = colors[index];
+                    let to_screen = |p| view.to_screen(area, p);
+                    match s.kind {
+                        SeriesKind::Line => {
+                            let mut previous = None;
+                            for i in 0..s.len() {
+                                let p = to_screen(s.point(i));
+                                if let Some(q) = previous {
+                                    if segment_visible(q, p, left, right) {
+                                        draw_list
+                                            .add_line(q, p, series_color)
+                                            .thickness(1.5)
+                                            .build();
+                                    }
+                                }
+                                previous = Some(p);
+                            }
+                        }
+                        SeriesKind::Scatter => {
+                            for i in 0..s.len() {
+                                let p = to_screen(s.point(i));
+                                if p.0 >= left - 3.0 && p.0 <= right + 3.0 {
+                                    draw_list
+                                        .add_circle(p, 2.5, series_color)
+                                        .num_segments(8)
+                                        .filled(true)
+                                        .build();
+                                }
+                            }
+                        }
+                        SeriesKind::Bars => {
+                            for i in 0..s.len() {
+                                let (px, py) = s.point(i);
+                                let half = s.bar_width * 0.5;
+                                let min = to_screen((px - half, py.max(0.0)));
+                                let max = to_screen((px + half, py.min(0.0)));
+                                if max.0 >= left && min.0 <= right {
+                                    draw_list
+                                        .add_rect(min, max, series_color)
+                                        .filled(true)
+                                        .build();
+                                }
+                            }
+                        }
+                    }
+                }
+                if let Some((index, point)) = hovered_point {
+                    let p = view.to_screen(area, point);
+                    draw_list
+                        .add_circle(p, 4.0, colors[index])
+                        .thickness(2.0)
+                        .build();
+                }
+            });
+
+            if let Some((origin, legend_size, ref entries)) = legend_entries {
+                let max = (origin.0 + legend_size.0, origin.1 + legend_size.1);
+                draw_list
+                    .add_rect(origin, max, color(ImGuiCol::PopupBg, 0.8))
+                    .filled(true)
+                    .build();
+                draw_list
+                    .add_rect(origin, max, color(ImGuiCol::Border, 1.0))
+                    .build();
+                for (index, &(ex, ey)) in entries.iter().enumerate() {
+                    let swatch = line_height * 0.8;
+                    let swatch_min = (ex, ey + (line_height - swatch) * 0.5);
+                    let swatch_max = (swatch_min.0 + swatch, swatch_min.1 + swatch);
+                    draw_list
+                        .add_rect(swatch_min, swatch_max, colors[index])
+                        .filled(visible[index])
+                        .build();
+                    let label_color = if visible[index] {
+                        text_color
+                    } else {
+                        color(ImGuiCol::TextDisabled, 1.0)
+                    };
+                    let label = series[index].label.to_str();
+                    let label = label.split("##").next().unwrap_or("");
+                    draw_list.add_text((ex + line_height * 1.5, ey), label_color, label);
+                }
+            }
+        }
+
+        if let Some((index, (px, py))) = hovered_point {
+            let label = series[index].label.to_str();
+            let label = label.split("##").next().unwrap_or("");
+            ui.tooltip_text(format!("{}\nx: {}\ny: {}", label, px, py));
+        }
+
+        // Lay out the plot as a single item, whatever the legend did to the cursor
+        ui.set_cursor_screen_pos((x, y));
+        ui.dummy((width, height));
+        ui.pop_id();
+    }
+}
+
+type Rect = ((f32, f32), (f32, f32));
+
+#[derive(Copy, Clone)]
+struct View {
+    x: (f32, f32),
+    y: (f32, f32),
+}
+
+impl View {
+    fn keys() -> [ImGuiID; 5] {
+        let key = |name: &[u8]| unsafe { sys::GetID(name.as_ptr() as *const c_char) };
+        [
+            key(b"##view\0"),
+            key(b"##x_min\0"),
+            key(b"##x_max\0"),
+            key(b"##y_min\0"),
+            key(b"##y_max\0"),
+        ]
+    }
+    // Returns the view set by the user, if any
+    fn load(storage: *mut ImGuiStorage) -> Option<View> {
+        let keys = View::keys();
+        unsafe {
+            if sys::ImGuiStorage_GetInt(storage, keys[0], 0) == 0 {
+                return None;
+            }
+            let get = |key| sys::ImGuiStorage_GetFloat(storage, key, 0.0);
+            Some(View {
+                x: (get(keys[1]), get(keys[2])),
+                y: (get(keys[3]), get(keys[4])),
+            })
+        }
+    }
+    fn store(&self, storage: *mut ImGuiStorage) {
+        let keys = View::keys();
+        unsafe {
+            sys::ImGuiStorage_SetInt(storage, keys[0], 1);
+            sys::ImGuiStorage_SetFloat(storage, keys[1], self.x.0);
+            sys::ImGuiStorage_SetFloat(storage, keys[2], self.x.1);
+            sys::ImGuiStorage_SetFloat(storage, keys[3], self.y.0);
+            sys::ImGuiStorage_SetFloat(storage, keys[4], self.y.1);
+        }
+    }
+    fn reset(storage: *mut ImGuiStorage) {
+        unsafe { sys::ImGuiStorage_SetInt(storage, View::keys()[0], 0) };
+    }
+    fn to_screen(&self, ((left, top), (right, bottom)): Rect, (px, py): (f32, f32)) -> (f32, f32) {
+        (
+            left + (px - self.x.0) / (self.x.1 - self.x.0) * (right - left),
+            bottom - (py - self.y.0) / (self.y.1 - self.y.0) * (bottom - top),
+        )
+    }
+    fn to_plot(&self, ((left, top), (right, bottom)): Rect, (sx, sy): (f32, f32)) -> (f32, f32) {
+        (
+            self.x.0 + (sx - left) / (right - left) * (self.x.1 - self.x.0),
+            self.y.0 + (bottom - sy) / (bottom - top) * (self.y.1 - self.y.0),
+        )
+    }
+}
+
+// The ranges containing all visible values, with some room above and below
+fn fit(series: &[PlotSeries], visible: &[bool]) -> View {
+    let mut x = (f32::MAX, f32::MIN);
+    let mut y = (f32::MAX, f32::MIN);
+    for (s, _) in series.iter().zip(visible).filter(|&(_, &v)| v) {
+        let half = if s.kind == SeriesKind::Bars {
+            y = (y.0.min(0.0), y.1.max(0.0));
+            s.bar_width * 0.5
+        } else {
+            0.0
+        };
+        for i in 0..s.len() {
+            let (px, py) = s.point(i);
+            if px.is_finite() && py.is_finite() {
+                x = (x.0.min(px - half), x.1.max(px + half));
+                y = (y.0.min(py), y.1.max(py));
+            }
+        }
+    }
+    let pad = |(min, max): (f32, f32), margin: f32| {
+        if min > max {
+            (0.0, 1.0)
+        } else if min == max {
+            (min - 0.5, max + 0.5)
+        } else {
+            let margin = (max - min) * margin;
+            (min - margin, max + margin)
+        }
+    };
+    View {
+        x: pad(x, 0.0),
+        y: pad(y, 0.05),
+    }
+}
+
+// Scales range around at, unless zooming in would bring its ends within a few ulps, or
+// zooming out would make its width overflow
+fn zoom(range: (f32, f32), at: f32, factor: f32) -> (f32, f32) {
+    let zoomed = ((range.0 - at) * factor + at, (range.1 - at) * factor + at);
+    let width = zoomed.1 - zoomed.0;
+    let min_width = (zoomed.0.abs().max(zoomed.1.abs()) * f32::EPSILON * 64.0).max(1e-30);
+    if factor < 1.0 && width < min_width {
+        range
+    } else if factor > 1.0 && !(width.is_finite() && width <= f32::MAX / 4.0) {
+        range
+    } else {
+        zoomed
+    }
+}
+
+const MAX_TICKS: usize = 100;
+
+// Round values between min and max, about max_count of them, with their labels
+fn ticks((min, max): (f32, f32), max_count: f32) -> Vec<(f32, String)> {
+    let range = max - min;
+    if !(range > 0.0) || !range.is_finite() {
+        return Vec::new();
+    }
+    let rough_step = range / max_count.max(1.0);
+    let magnitude = 10f32.powf(rough_step.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|&step| step >= rough_step)
+        .unwrap_or(10.0 * magnitude);
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    // Counted with an integer, as adding 1.0 to a large f32 no longer changes it
+    let first = (min / step).ceil() * step;
+    let count = cmp::min((range / step) as usize + 1, MAX_TICKS);
+    let mut ticks = (0..count)
+        .map(|i| first + i as f32 * step)
+        .take_while(|&value| value <= max + step * 1e-3)
+        .map(|value| {
+            // Avoid "-0" labels
+            let value = if value.abs() < step * 1e-3 {
+                0.0
+            } else {
+                value
+            };
+            (value, format!("{:.*}", decimals, value))
+        })
+        .collect::<Vec<_>>();
+    // Steps smaller than the precision of the values give the same tick several times
+    ticks.dedup_by(|a, b| a.0 == b.0);
+    ticks
+}
+
+fn segment_visible(p: (f32, f32), q: (f32, f32), left: f32, right: f32) -> bool {
+    !(p.0 < left && q.0 < left) && !(p.0 > right && q.0 > right)
+}
+
+// The visible value closest to the mouse, with the index of its series
+fn nearest<F: Fn((f32, f32)) -> (f32, f32)>(
+    series: &[PlotSeries],
+    visible: &[bool],
+    to_screen: F,
+    mouse_pos: (f32, f32),
+) -> Option<(usize, (f32, f32))> {
+    let mut nearest = None;
+    let mut nearest_distance = f32::MAX;
+    for (index, s) in series.iter().enumerate() {
+        if !visible[index] {
+            continue;
+        }
+        for i in 0..s.len() {
+            let point = s.point(i);
+            let (sx, sy) = to_screen(point);
+            let distance = (sx - mouse_pos.0).powi(2) + (sy - mouse_pos.1).powi(2);
+            if distance < nearest_distance {
+                nearest_distance = distance;
+                nearest = Some((index, point));
+            }
+        }
+    }
+    nearest
+}
@@ -1,14 +1,13 @@
 use std::marker::PhantomData;
-use std::os::raw::c_float;
-use std::{f32, mem, ptr};
+use std::{f32, ptr};
 use sys;
 
-use super::{ImStr, ImVec2, Ui};
+use super::{ImStr, ImVec2, PlotValues, Ui};
 
 #[must_use]
 pub struct PlotLines<'ui, 'p> {
     label: &'p ImStr,
-    values: &'p [f32],
+    values: PlotValues<'p>,
     values_offset: usize,
     overlay_text: Option<&'p ImStr>,
     scale_min: f32,
@@ -18,7 +17,12 @@ pub struct PlotLines<'ui, 'p> {
 }
 
 impl<'ui, 'p> PlotLines<'ui, 'p> {
-    pub fn new(_: &Ui<'ui>, label: &'p ImStr, values: &'p [f32]) -> Self {
+    pub fn new(ui: &Ui<'ui>, label: &'p ImStr, values: &'p [f32]) -> Self {
+        PlotLines::with_values(ui, label, values.into())
+    }
+
+    /// Plots values read in place from a field of a slice of structs or from a closure.
+    pub fn with_values(_: &Ui<'ui>, label: &'p ImStr, values: PlotValues<'p>) -> Self {
         PlotLines {
             label: label,
             values: values,
@@ -31,6 +35,8 @@ impl<'ui, 'p> PlotLines<'ui, 'p> {
         }
     }
 
+    /// Sets the index of the first value to plot, wrapping around, to plot a ring buffer in
+    /// order.
     #[inline]
     pub fn values_offset(mut self, values_offset: usize) -> Self {
         self.values_offset = values_offset;
@@ -62,18 +68,34 @@ impl<'ui, 'p> PlotLines<'ui, 'p> {
     }
 
     pub fn build(self) {
-        unsafe {
-            sys::PlotLines(
-                self.label.as_ptr(),
-                self.values.as_ptr() as *const c_float,
-                self.values.len() as i32,
-                self.values_offset as i32,
-                self.overlay_text.map(|x| x.as_ptr()).unwrap_or(ptr::null()),
-                self.scale_min,
-                self.scale_max,
-                self.graph_size,
-                mem::size_of::<f32>() as i32,
-            );
-        }
+        let overlay_text = self.overlay_text.map(|x| x.as_ptr()).unwrap_or(ptr::null());
+        self.values.with_raw(
+            |values, count, stride| unsafe {
+                sys::PlotLines(
+                    self.label.as_ptr(),
+                    values,
+                    count,
+                    self.values_offset as i32,
+                    overlay_text,
+                    self.scale_min,
+                    self.scale_max,
+                    self.graph_size,
+                    stride,
+                )
+            },
+            |getter, data, count| unsafe {
+                sys::PlotLines1(
+                    self.label.as_ptr(),
+                    Some(getter),
+                    data,
+                    count,
+                    self.values_offset as i32,
+                    overlay_text,
+                    self.scale_min,
+                    self.scale_max,
+                    self.graph_size,
+                )
+            },
+        );
     }
 }
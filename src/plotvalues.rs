@@ -0,0 +1,162 @@
+use std::any::Any;
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::{c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Values plotted by `PlotLines` and `PlotHistogram`, read in place without copying.
+///
+/// Values can come from a slice of `f32`, from an `f32` field of a slice of structs, or from a
+/// closure called with each index.
+///
+/// # Example
+/// ```rust
+/// # use imgui::*;
+/// struct Sample {
+///     time: f64,
+///     latency: f32,
+/// }
+///
+/// let samples = vec![
+///     Sample { time: 0.0, latency: 12.0 },
+///     Sample { time: 0.5, latency: 15.5 },
+/// ];
+/// let latency = PlotValues::field(&samples, |s| &s.latency);
+/// assert_eq!(latency.len(), 2);
+///
+/// let getter = |i: usize| samples[i].latency * 2.0;
+/// let doubled = PlotValues::getter(samples.len(), &getter);
+/// assert_eq!(doubled.len(), 2);
+/// ```
+#[derive(Copy, Clone)]
+pub struct PlotValues<'p> {
+    kind: ValuesKind<'p>,
+    _phantom: PhantomData<&'p [f32]>,
+}
+
+#[derive(Copy, Clone)]
+enum ValuesKind<'p> {
+    Strided {
+        // Derived from the whole slice, as imgui reads every value through it
+        first: *const f32,
+        count: usize,
+        stride: usize,
+    },
+    Getter {
+        count: usize,
+        getter: &'p Fn(usize) -> f32,
+    },
+}
+
+impl<'p> PlotValues<'p> {
+    /// Reads the `f32` field of each struct in `values` selected by `field`, which must return
+    /// a reference into the struct it is given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field` returns a reference outside of its argument.
+    pub fn field<T>(values: &'p [T], field: fn(&T) -> &f32) -> PlotValues<'p> {
+        let offset = match values.first() {
+            Some(value) => {
+                let start = value as *const T as usize;
+                let offset = (field(value) as *const f32 as usize).wrapping_sub(start);
+                let end = offset.checked_add(mem::size_of::<f32>());
+                assert!(
+                    end.map(|end| end <= mem::size_of::<T>()).unwrap_or(false),
+                    "PlotValues::field must return a field of its argument"
+                );
+                offset
+            }
+            None => 0,
+        };
+        PlotValues {
+            kind: ValuesKind::Strided {
+                first: unsafe {
+                    (values.as_ptr() as *const u8).offset(offset as isize) as *const f32
+                },
+                count: values.len(),
+                stride: mem::size_of::<T>(),
+            },
+            _phantom: PhantomData,
+        }
+    }
+    /// Reads each value by calling `getter` with its index, below `count`.
+    ///
+    /// If `getter` panics, the values left are read as 0 and the panic resumes once imgui
+    /// returns.
+    pub fn getter<F: Fn(usize) -> f32>(count: usize, getter: &'p F) -> PlotValues<'p> {
+        PlotValues {
+            kind: ValuesKind::Getter { count, getter },
+            _phantom: PhantomData,
+        }
+    }
+    pub fn len(&self) -> usize {
+        match self.kind {
+            ValuesKind::Strided { count, .. } | ValuesKind::Getter { count, .. } => count,
+        }
+    }
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+    /// Calls `strided` with a pointer to the first value, the count and the byte stride, or
+    /// `callback` with an imgui values getter, its data and the count.
+    pub(crate) fn with_raw<R, S, C>(&self, strided: S, callback: C) -> R
+    where
+        S: FnOnce(*const f32, c_int, c_int) -> R,
+        C: FnOnce(ValuesGetter, *mut c_void, c_int) -> R,
+    {
+        // Panics must not unwind through imgui, so they are caught and resumed afterwards
+        unsafe extern "C" fn values_getter(data: *mut c_void, idx: c_int) -> f32 {
+            let data = &mut *(data as *mut GetterData);
+            if data.panic.is_some() {
+                return 0.0;
+            }
+            let getter = data.getter;
+            match panic::catch_unwind(AssertUnwindSafe(|| getter(idx as usize))) {
+                Ok(value) => value,
+                Err(payload) => {
+                    data.panic = Some(payload);
+                    0.0
+                }
+            }
+        }
+
+        match self.kind {
+            ValuesKind::Strided {
+                first,
+                count,
+                stride,
+            } => strided(first, count as c_int, stride as c_int),
+            ValuesKind::Getter { count, getter } => {
+                let mut data = GetterData {
+                    getter,
+                    panic: None,
+                };
+                let data_ptr = &mut data as *mut GetterData as *mut c_void;
+                let result = callback(values_getter, data_ptr, count as c_int);
+                if let Some(payload) = data.panic {
+                    panic::resume_unwind(payload);
+                }
+                result
+            }
+        }
+    }
+}
+
+pub(crate) type ValuesGetter = unsafe extern "C" fn(*mut c_void, c_int) -> f32;
+
+struct GetterData<'p> {
+    getter: &'p Fn(usize) -> f32,
+    panic: Option<Box<Any + Send>>,
+}
+
+impl<'p> From<&'p [f32]> for PlotValues<'p> {
+    fn from(values: &'p [f32]) -> Self {
+        PlotValues {
+            kind: ValuesKind::Strided {
+                first: values.as_ptr(),
+                count: values.len(),
+                stride: mem::size_of::<f32>(),
+            },
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use std::fmt;
+use sys;
+
+use super::{ImStr, ImString, Key, Ui};
+
+// Every `Key` needs a name, used both to display and to parse shortcuts
+static KEY_NAMES: &'static [(Key, &'static str)] = &[
+    (Key::Tab, "Tab"),
+    (Key::LeftArrow, "Left"),
+    (Key::RightArrow, "Right"),
+    (Key::UpArrow, "Up"),
+    (Key::DownArrow, "Down"),
+    (Key::PageUp, "PageUp"),
+    (Key::PageDown, "PageDown"),
+    (Key::Home, "Home"),
+    (Key::End, "End"),
+    (Key::Insert, "Insert"),
+    (Key::Delete, "Delete"),
+    (Key::Backspace, "Backspace"),
+    (Key::Space, "Space"),
+    (Key::Enter, "Enter"),
+    (Key::Escape, "Escape"),
+    (Key::A, "A"),
+    (Key::B, "B"),
+    (Key::C, "C"),
+    (Key::D, "D"),
+    (Key::E, "E"),
+    (Key::F, "F"),
+    (Key::G, "G"),
+    (Key::H, "H"),
+    (Key::I, "I"),
+    (Key::J, "J"),
+    (Key::K, "K"),
+    (Key::L, "L"),
+    (Key::M, "M"),
+    (Key::N, "N"),
+    (Key::O, "O"),
+    (Key::P, "P"),
+    (Key::Q, "Q"),
+    (Key::R, "R"),
+    (Key::S, "S"),
+    (Key::T, "T"),
+    (Key::U, "U"),
+    (Key::V, "V"),
+    (Key::W, "W"),
+    (Key::X, "X"),
+    (Key::Y, "Y"),
+    (Key::Z, "Z"),
+    (Key::Key0, "0"),
+    (Key::Key1, "1"),
+    (Key::Key2, "2"),
+    (Key::Key3, "3"),
+    (Key::Key4, "4"),
+    (Key::Key5, "5"),
+    (Key::Key6, "6"),
+    (Key::Key7, "7"),
+    (Key::Key8, "8"),
+    (Key::Key9, "9"),
+    (Key::F1, "F1"),
+    (Key::F2, "F2"),
+    (Key::F3, "F3"),
+    (Key::F4, "F4"),
+    (Key::F5, "F5"),
+    (Key::F6, "F6"),
+    (Key::F7, "F7"),
+    (Key::F8, "F8"),
+    (Key::F9, "F9"),
+    (Key::F10, "F10"),
+    (Key::F11, "F11"),
+    (Key::F12, "F12"),
+    (Key::Apostrophe, "'"),
+    (Key::Comma, ","),
+    (Key::Minus, "-"),
+    (Key::Period, "."),
+    (Key::Slash, "/"),
+    (Key::Semicolon, ";"),
+    (Key::Equal, "="),
+    (Key::LeftBracket, "["),
+    (Key::Backslash, "\\"),
+    (Key::RightBracket, "]"),
+    (Key::GraveAccent, "`"),
+    (Key::CapsLock, "CapsLock"),
+    (Key::ScrollLock, "ScrollLock"),
+    (Key::NumLock, "NumLock"),
+    (Key::PrintScreen, "PrintScreen"),
+    (Key::Pause, "Pause"),
+    (Key::Keypad0, "Keypad0"),
+    (Key::Keypad1, "Keypad1"),
+    (Key::Keypad2, "Keypad2"),
+    (Key::Keypad3, "Keypad3"),
+    (Key::Keypad4, "Keypad4"),
+    (Key::Keypad5, "Keypad5"),
+    (Key::Keypad6, "Keypad6"),
+    (Key::Keypad7, "Keypad7"),
+    (Key::Keypad8, "Keypad8"),
+    (Key::Keypad9, "Keypad9"),
+    (Key::KeypadDecimal, "KeypadDecimal"),
+    (Key::KeypadDivide, "KeypadDivide"),
+    (Key::KeypadMultiply, "KeypadMultiply"),
+    (Key::KeypadSubtract, "KeypadSubtract"),
+    (Key::KeypadAdd, "KeypadAdd"),
+    (Key::KeypadEnter, "KeypadEnter"),
+    (Key::KeypadEqual, "KeypadEqual"),
+    (Key::LeftCtrl, "LeftCtrl"),
+    (Key::RightCtrl, "RightCtrl"),
+    (Key::LeftShift, "LeftShift"),
+    (Key::RightShift, "RightShift"),
+    (Key::LeftAlt, "LeftAlt"),
+    (Key::RightAlt, "RightAlt"),
+    (Key::LeftSuper, "LeftSuper"),
+    (Key::RightSuper, "RightSuper"),
+    (Key::Menu, "Menu"),
+];
+
+/// A key combined with modifiers, such as `Ctrl+Shift+S`.
+///
+/// # Example
+/// ```rust
+/// # use imgui::*;
+/// let shortcut = Shortcut::parse("ctrl+shift+s").unwrap();
+/// assert_eq!(shortcut, Shortcut::new(Key::S).ctrl().shift());
+/// assert_eq!(shortcut.to_string(), "Ctrl+Shift+S");
+/// assert_eq!(Shortcut::parse("Ctrl+"), None);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Shortcut {
+    key: Key,
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+    super_: bool,
+}
+
+impl Shortcut {
+    /// Creates a shortcut for `key` without modifiers.
+    pub fn new(key: Key) -> Shortcut {
+        Shortcut {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+            super_: false,
+        }
+    }
+    /// Parses a chord of `+`-separated modifiers followed by a key, ignoring case.
+    ///
+    /// Modifiers are `Ctrl`, `Shift`, `Alt` and `Super` (also `Cmd` or `Win`). Keys are named
+    /// as in `Display`, with `Esc`, `Del` and `Return` accepted as aliases.
+    pub fn parse(chord: &str) -> Option<Shortcut> {
+        let parts = chord.split('+').map(|part| part.trim()).collect::<Vec<_>>();
+        let (key_name, modifiers) = match parts.split_last() {
+            Some(split) => split,
+            None => return None,
+        };
+        let mut shortcut = match parse_key(key_name) {
+            Some(key) => Shortcut::new(key),
+            None => return None,
+        };
+        for modifier in modifiers {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => shortcut.ctrl = true,
+                "shift" => shortcut.shift = true,
+                "alt" | "option" => shortcut.alt = true,
+                "super" | "cmd" | "win" => shortcut.super_ = true,
+                _ => return None,
+            }
+        }
+        Some(shortcut)
+    }
+    #[inline]
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+    #[inline]
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+    #[inline]
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+    #[inline]
+    pub fn super_(mut self) -> Self {
+        self.super_ = true;
+        self
+    }
+    pub fn key(&self) -> Key { self.key }
+    /// Returns `true` if the key was pressed this frame with exactly these modifiers held.
+    pub fn is_pressed(&self, ui: &Ui) -> bool {
+        let imgui = ui.imgui();
+        imgui.key_ctrl() == self.ctrl
+            && imgui.key_shift() == self.shift
+            && imgui.key_alt() == self.alt
+            && imgui.key_super() == self.super_
+            && ui.is_key_pressed_no_repeat(self.key)
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let modifiers = [
+            (self.ctrl, "Ctrl+"),
+            (self.shift, "Shift+"),
+            (self.alt, "Alt+"),
+            (self.super_, "Super+"),
+        ];
+        for &(held, name) in modifiers.iter() {
+            if held {
+                f.write_str(name)?;
+            }
+        }
+        f.write_str(key_name(self.key))
+    }
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    let name = name.to_lowercase();
+    let name = match name.as_str() {
+        "esc" => "escape",
+        "del" => "delete",
+        "return" => "enter",
+        name => name,
+    };
+    KEY_NAMES
+        .iter()
+        .find(|&&(_, key_name)| key_name.to_lowercase() == name)
+        .map(|&(key, _)| key)
+}
+
+fn key_name(key: Key) -> &'static str {
+    KEY_NAMES
+        .iter()
+        .find(|&&(k, _)| k == key)
+        .map(|&(_, name)| name)
+        .unwrap_or("?")
+}
+
+/// Where a shortcut is active.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ShortcutScope {
+    /// Active unless imgui wants the keyboard, checked with `Shortcuts::pressed`.
+    Global,
+    /// Active while the current window or one of its children is focused, unless a text field
+    /// is being edited. Checked with `Shortcuts::pressed_in_window` inside the window.
+    Window,
+}
+
+struct Binding<A> {
+    action: A,
+    shortcut: Shortcut,
+    scope: ShortcutScope,
+    label: ImString,
+}
+
+/// A registry binding application actions to keyboard shortcuts.
+///
+/// The same bindings trigger actions and label menu items through `MenuItem::shortcut_for`,
+/// so the displayed shortcuts always match the real ones.
+///
+/// # Example
+/// ```rust
+/// # use imgui::*;
+/// #[derive(Copy, Clone, PartialEq)]
+/// enum Action {
+///     Save,
+///     Find,
+/// }
+///
+/// let mut shortcuts = Shortcuts::new();
+/// shortcuts.bind(Action::Save, Shortcut::parse("Ctrl+S").unwrap());
+/// let find = Shortcut::parse("Ctrl+F").unwrap();
+/// shortcuts.bind_scoped(Action::Find, find, ShortcutScope::Window);
+/// assert_eq!(shortcuts.label(Action::Save).map(|l| l.to_str()), Some("Ctrl+S"));
+///
+/// fn run_ui(ui: &Ui, shortcuts: &Shortcuts<Action>) {
+///     ui.main_menu_bar(|| {
+///         ui.menu(im_str!("File")).build(|| {
+///             if ui.menu_item(im_str!("Save"))
+///                 .shortcut_for(shortcuts, Action::Save)
+///                 .build()
+///             {
+///                 // save
+///             }
+///         });
+///     });
+///     if let Some(Action::Save) = shortcuts.pressed(ui) {
+///         // save
+///     }
+/// }
+/// ```
+pub struct Shortcuts<A> {
+    bindings: Vec<Binding<A>>,
+}
+
+impl<A: Copy + PartialEq> Shortcuts<A> {
+    pub fn new() -> Shortcuts<A> {
+        Shortcuts {
+            bindings: Vec::new(),
+        }
+    }
+    /// Binds `action` to a global shortcut, replacing any previous binding of the action.
+    pub fn bind(&mut self, action: A, shortcut: Shortcut) {
+        self.bind_scoped(action, shortcut, ShortcutScope::Global);
+    }
+    /// Binds `action` to a shortcut active in `scope`, replacing any previous binding of the
+    /// action.
+    pub fn bind_scoped(&mut self, action: A, shortcut: Shortcut, scope: ShortcutScope) {
+        self.unbind(action);
+        self.bindings.push(Binding {
+            action,
+            shortcut,
+            scope,
+            label: ImString::new(shortcut.to_string()),
+        });
+    }
+    pub fn unbind(&mut self, action: A) { self.bindings.retain(|b| b.action != action); }
+    pub fn shortcut(&self, action: A) -> Option<Shortcut> {
+        self.binding(action).map(|b| b.shortcut)
+    }
+    /// Returns the label of the shortcut bound to `action`, as shown in menus.
+    pub fn label(&self, action: A) -> Option<&ImStr> { self.binding(action).map(|b| &*b.label) }
+    /// Returns the global action whose shortcut was pressed this frame, if any.
+    ///
+    /// Nothing is triggered while imgui wants the keyboard, for example while a text field
+    /// is being edited or keyboard navigation is active.
+    pub fn pressed(&self, ui: &Ui) -> Option<A> {
+        if ui.want_capture_keyboard() {
+            return None;
+        }
+        self.find_pressed(ui, ShortcutScope::Global)
+    }
+    /// Returns the window-scoped action whose shortcut was pressed this frame, if any. Must
+    /// be called inside the window the shortcuts belong to.
+    pub fn pressed_in_window(&self, ui: &Ui) -> Option<A> {
+        let focused = unsafe { sys::IsWindowFocused(sys::ImGuiFocusedFlags::RootAndChildWindows) };
+        if !focused || ui.imgui().want_text_input() {
+            return None;
+        }
+        self.find_pressed(ui, ShortcutScope::Window)
+    }
+    fn binding(&self, action: A) -> Option<&Binding<A>> {
+        self.bindings.iter().find(|b| b.action == action)
+    }
+    fn find_pressed(&self, ui: &Ui, scope: ShortcutScope) -> Option<A> {
+        self.bindings
+            .iter()
+            .find(|b| b.scope == scope && b.shortcut.is_pressed(ui))
+            .map(|b| b.action)
+    }
+}
+
+impl<A: Copy + PartialEq> Default for Shortcuts<A> {
+    fn default() -> Self { Shortcuts::new() }
+}
@@ -0,0 +1,317 @@
+use std::os::raw::{c_char, c_int};
+use sys;
+
+use super::{ImGuiCol, ImId, ImMouseButton, ImStr, ImVec2, Ui};
+
+/// A tab of a `TabBar`.
+///
+/// Tabs are identified by their label, so use `##` to give tabs with the same label
+/// different IDs, e.g. `"Untitled##1"` and `"Untitled##2"`.
+#[derive(Copy, Clone, Debug)]
+pub struct Tab<'p> {
+    label: &'p ImStr,
+    closable: bool,
+    unsaved: bool,
+}
+
+impl<'p> Tab<'p> {
+    pub fn new(label: &'p ImStr) -> Tab<'p> {
+        Tab {
+            label,
+            closable: false,
+            unsaved: false,
+        }
+    }
+    /// Shows a close button on the tab. Closing is requested by clicking it or by
+    /// middle-clicking the tab.
+    #[inline]
+    pub fn closable(mut self, value: bool) -> Self {
+        self.closable = value;
+        self
+    }
+    /// Shows a marker after the label, for documents with unsaved changes.
+    #[inline]
+    pub fn unsaved(mut self, value: bool) -> Self {
+        self.unsaved = value;
+        self
+    }
+}
+
+/// What happened to a `TabBar` this frame.
+///
+/// Tabs are never closed or moved by the tab bar itself: the caller applies
+/// `close_requested` and `moved` to its own list of tabs.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct TabBarResult {
+    /// The index of the selected tab, or `None` if there are no tabs.
+    pub selected: Option<usize>,
+    /// `true` if the selected tab was changed by the user or with `TabBar::select`.
+    pub selection_changed: bool,
+    /// The index of a tab the user asked to close.
+    pub close_requested: Option<usize>,
+    /// A tab dragged from the first index to the second one, which are next to each other.
+    pub moved: Option<(usize, usize)>,
+}
+
+/// A row of tabs, of which one is selected.
+///
+/// The selected tab is stored in imgui's state storage under the tab bar ID, so it is
+/// remembered across frames. Tabs overflowing the available width scroll horizontally.
+///
+/// # Example
+/// ```rust,no_run
+/// # #[macro_use] extern crate imgui;
+/// # use imgui::*;
+/// struct Document {
+///     name: ImString,
+///     modified: bool,
+/// }
+///
+/// fn documents(ui: &Ui, docs: &mut Vec<Document>) {
+///     let result = {
+///         let tabs = docs
+///             .iter()
+///             .map(|doc| Tab::new(&doc.name).closable(true).unsaved(doc.modified))
+///             .collect::<Vec<_>>();
+///         ui.tab_bar("documents").reorderable(true).build(&tabs)
+///     };
+///     if let Some(index) = result.selected {
+///         ui.text(format!("Editing {}", docs[index].name.to_str()));
+///     }
+///     if let Some((from, to)) = result.moved {
+///         docs.swap(from, to);
+///     }
+///     if let Some(index) = result.close_requested {
+///         docs.remove(index);
+///     }
+/// }
+/// # fn main() {
+/// # }
+/// ```
+#[must_use]
+pub struct TabBar<'ui, 'p> {
+    ui: &'ui Ui<'ui>,
+    id: ImId<'p>,
+    reorderable: bool,
+    select: Option<usize>,
+}
+
+impl<'ui, 'p> TabBar<'ui, 'p> {
+    pub fn new<I: Into<ImId<'p>>>(ui: &'ui Ui<'ui>, id: I) -> TabBar<'ui, 'p> {
+        TabBar {
+            ui,
+            id: id.into(),
+            reorderable: false,
+            select: None,
+        }
+    }
+    /// Allows tabs to be reordered by dragging them.
+    #[inline]
+    pub fn reorderable(mut self, value: bool) -> Self {
+        self.reorderable = value;
+        self
+    }
+    /// Selects the tab at `index` and scrolls it into view, for example when opening a new
+    /// document.
+    #[inline]
+    pub fn select(mut self, index: usize) -> Self {
+        self.select = Some(index);
+        self
+    }
+    pub fn build(self, tabs: &[Tab]) -> TabBarResult {
+        let TabBar {
+            ui,
+            id,
+            reorderable,
+            select,
+        } = self;
+        let mut result = TabBarResult::default();
+        ui.push_id(id);
+        let (frame_padding, scrollbar_size) = {
+            let style = ui.imgui().style();
+            (style.FramePadding, style.ScrollbarSize)
+        };
+        let height = unsafe { sys::GetFrameHeight() };
+        let region_height = height + scrollbar_size;
+        ui.child_window(unsafe { ImStr::from_utf8_with_nul_unchecked(b"##tabs\0") })
+            .size((0.0, region_height))
+            .horizontal_scrollbar(true)
+            .scroll_bar(false)
+            .build(|| {
+                if tabs.is_empty() {
+                    return;
+                }
+                let storage = unsafe { sys::GetStateStorage() };
+                let selected_key = unsafe { sys::GetID(b"##selected\0".as_ptr() as *const c_char) };
+                let ids = tabs
+                    .iter()
+                    .map(|tab| unsafe { sys::GetID(tab.label.as_ptr()) })
+                    .collect::<Vec<_>>();
+                let stored = unsafe { sys::ImGuiStorage_GetInt(storage, selected_key, 0) };
+                let mut selected = ids
+                    .iter()
+                    .position(|&id| id as c_int == stored)
+                    .unwrap_or(0);
+                let mut scroll_to_selected = false;
+                if let Some(index) = select {
+                    if index < tabs.len() && index != selected {
+                        selected = index;
+                        result.selection_changed = true;
+                    }
+                    scroll_to_selected = true;
+                }
+
+                for (index, tab) in tabs.iter().enumerate() {
+                    if index > 0 {
+                        ui.same_line_spacing(0.0, 1.0);
+                    }
+                    let response = draw_tab(ui, tab, index == selected, frame_padding, height);
+                    if response.clicked && index != selected {
+                        selected = index;
+                        result.selection_changed = true;
+                        scroll_to_selected = true;
+                    }
+                    if response.close {
+                        result.close_requested = Some(index);
+                    }
+                    if reorderable && response.dragged_out {
+                        let (dx, _) = ui.mouse_drag_delta(ImMouseButton::Left);
+                        let target = if dx < 0.0 && index > 0 {
+                            Some(index - 1)
+                        } else if dx > 0.0 && index + 1 < tabs.len() {
+                            Some(index + 1)
+                        } else {
+                            None
+                        };
+                        if let Some(target) = target {
+                            result.moved = Some((index, target));
+                            ui.reset_mouse_drag_delta(ImMouseButton::Left);
+                        }
+                    }
+                    if index == selected && scroll_to_selected {
+                        scroll_into_view(ui, response.min_x, response.max_x);
+                    }
+                }
+
+                unsafe { sys::ImGuiStorage_SetInt(storage, selected_key, ids[selected] as c_int) };
+                result.selected = Some(selected);
+            });
+        ui.pop_id();
+        result
+    }
+}
+
+struct TabResponse {
+    clicked: bool,
+    close: bool,
+    dragged_out: bool,
+    // Horizontal extent in window coordinates, used for scrolling
+    min_x: f32,
+    max_x: f32,
+}
+
+fn draw_tab(ui: &Ui, tab: &Tab, selected: bool, padding: ImVec2, height: f32) -> TabResponse {
+    let label = tab.label.to_str();
+    let text = label.split("##").next().unwrap_or("");
+    let text_size = ui.calc_text_size(tab.label, true, -1.0);
+    let marker_radius = text_size.y * 0.2;
+    let close_radius = text_size.y * 0.5;
+    let mut width = padding.x * 2.0 + text_size.x;
+    if tab.unsaved {
+        width += padding.x + marker_radius * 2.0;
+    }
+    if tab.closable {
+        width += padding.x + close_radius * 2.0;
+    }
+
+    let (min_x, _) = ui.get_cursor_pos();
+    let (x, y) = ui.get_cursor_screen_pos();
+    ui.push_id(label);
+    let tab_id = unsafe { ImStr::from_utf8_with_nul_unchecked(b"##tab\0") };
+    let clicked = ui.invisible_button(tab_id, (width, height));
+    let hovered = ui.is_item_hovered();
+    let active = ui.is_item_active();
+    ui.set_item_allow_overlap();
+    let mut close = tab.closable && hovered && ui.imgui().is_mouse_clicked(ImMouseButton::Middle);
+    let mut close_hovered = false;
+    let close_center = (x + width - padding.x - close_radius, y + height * 0.5);
+    if tab.closable {
+        ui.set_cursor_screen_pos((close_center.0 - close_radius, close_center.1 - close_radius));
+        let close_id = unsafe { ImStr::from_utf8_with_nul_unchecked(b"##close\0") };
+        if ui.invisible_button(close_id, (close_radius * 2.0, close_radius * 2.0)) {
+            close = true;
+        }
+        close_hovered = ui.is_item_hovered();
+    }
+    ui.pop_id();
+
+    let color = |col: ImGuiCol| unsafe { sys::GetColorU32(col, 1.0) };
+    let bg_color = if selected || active {
+        color(ImGuiCol::HeaderActive)
+    } else if hovered {
+        color(ImGuiCol::HeaderHovered)
+    } else {
+        color(ImGuiCol::Header)
+    };
+    let text_color = color(ImGuiCol::Text);
+    let rounding = ui.imgui().style().FrameRounding;
+    {
+        let draw_list = ui.get_window_draw_list();
+        draw_list
+            .add_rect((x, y), (x + width, y + height), bg_color)
+            .filled(true)
+            .rounding(rounding)
+            .round_bot_left(false)
+            .round_bot_right(false)
+            .build();
+        draw_list.add_text((x + padding.x, y + padding.y), text_color, text);
+        if tab.unsaved {
+            let center = (
+                x + padding.x * 2.0 + text_size.x + marker_radius,
+                y + height * 0.5,
+            );
+            draw_list
+                .add_circle(center, marker_radius, text_color)
+                .filled(true)
+                .build();
+        }
+        if tab.closable {
+            let (cx, cy) = close_center;
+            if close_hovered {
+                draw_list
+                    .add_circle(close_center, close_radius, color(ImGuiCol::ButtonHovered))
+                    .filled(true)
+                    .build();
+            }
+            let r = close_radius * 0.5;
+            draw_list
+                .add_line((cx - r, cy - r), (cx + r, cy + r), text_color)
+                .build();
+            draw_list
+                .add_line((cx + r, cy - r), (cx - r, cy + r), text_color)
+                .build();
+        }
+    }
+
+    // Lay out the tab as a single item, whatever the close button did to the cursor
+    ui.set_cursor_screen_pos((x, y));
+    ui.dummy((width, height));
+
+    TabResponse {
+        clicked,
+        close,
+        dragged_out: active && !hovered,
+        min_x,
+        max_x: min_x + width,
+    }
+}
+
+fn scroll_into_view(ui: &Ui, min_x: f32, max_x: f32) {
+    let scroll_x = ui.get_scroll_x();
+    let visible_width = ui.get_window_content_region_width();
+    if min_x < scroll_x {
+        ui.set_scroll_x(min_x);
+    } else if max_x > scroll_x + visible_width {
+        ui.set_scroll_x(max_x - visible_width);
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use super::{ImGuiCond, ImStr, ImString, Key, Ui};
+
+/// The hierarchy shown by a `TreeView`.
+///
+/// Children are only requested for open nodes, so they can be loaded the first time a node
+/// is expanded.
+pub trait TreeSource {
+    /// Identifies a node. IDs must be unique in the whole tree, and stay the same across
+    /// frames for nodes to keep their open state.
+    type Id: Clone + Eq + Hash;
+
+    fn roots(&mut self) -> Vec<Self::Id>;
+    fn label(&self, id: &Self::Id) -> ImString;
+    /// Returns `true` if the node can be expanded, without loading its children.
+    fn has_children(&self, id: &Self::Id) -> bool;
+    fn children(&mut self, id: &Self::Id) -> Vec<Self::Id>;
+}
+
+// A node drawn this frame, in display order
+struct VisibleNode<Id> {
+    id: Id,
+    parent: Option<usize>,
+    has_children: bool,
+    open: bool,
+}
+
+struct Click<Id> {
+    id: Id,
+    ctrl: bool,
+    shift: bool,
+}
+
+/// A tree of selectable nodes, keeping its selection across frames.
+///
+/// Nodes are selected by clicking them. With multi-selection, Ctrl+click toggles a node and
+/// Shift+click selects the range of visible nodes from the last clicked one.
+///
+/// While the window containing the tree is focused, the up and down arrow keys move the
+/// selection, the right arrow key expands the current node or moves to its first child, and
+/// the left arrow key collapses it or moves to its parent.
+///
+/// # Example
+/// ```rust,no_run
+/// # #[macro_use] extern crate imgui;
+/// # use imgui::*;
+/// struct Scene {
+///     names: Vec<ImString>,
+///     children: Vec<Vec<usize>>,
+/// }
+///
+/// impl TreeSource for Scene {
+///     type Id = usize;
+///
+///     fn roots(&mut self) -> Vec<usize> { vec![0] }
+///     fn label(&self, id: &usize) -> ImString { self.names[*id].clone() }
+///     fn has_children(&self, id: &usize) -> bool { !self.children[*id].is_empty() }
+///     fn children(&mut self, id: &usize) -> Vec<usize> { self.children[*id].clone() }
+/// }
+///
+/// fn hierarchy(ui: &Ui, view: &mut TreeView<usize>, scene: &mut Scene) {
+///     ui.window(im_str!("Hierarchy")).build(|| {
+///         if view.build(ui, scene) {
+///             println!("Selected {:?}", view.selection());
+///         }
+///     });
+/// }
+/// # fn main() {
+/// let mut view = TreeView::<usize>::new();
+/// view.set_multi_select(true);
+/// # }
+/// ```
+pub struct TreeView<Id> {
+    selection: Vec<Id>,
+    // The node Shift+click and Shift+arrow select from
+    anchor: Option<Id>,
+    // The node moved by the arrow keys
+    cursor: Option<Id>,
+    multi_select: bool,
+    pending_open: Vec<(Id, bool)>,
+    scroll_to: Option<Id>,
+}
+
+impl<Id: Clone + Eq + Hash> TreeView<Id> {
+    pub fn new() -> TreeView<Id> {
+        TreeView {
+            selection: Vec::new(),
+            anchor: None,
+            cursor: None,
+            multi_select: false,
+            pending_open: Vec::new(),
+            scroll_to: None,
+        }
+    }
+    /// Allows selecting several nodes with Ctrl+click and Shift+click.
+    pub fn set_multi_select(&mut self, value: bool) { self.multi_select = value; }
+    /// Returns the selected nodes, in the order they were selected.
+    pub fn selection(&self) -> &[Id] { &self.selection }
+    pub fn is_selected(&self, id: &Id) -> bool { self.selection.contains(id) }
+    /// Selects only `id`, which is scrolled into view on the next frame.
+    pub fn select(&mut self, id: Id) {
+        self.selection = vec![id.clone()];
+        self.anchor = Some(id.clone());
+        self.cursor = Some(id.clone());
+        self.scroll_to = Some(id);
+    }
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.anchor = None;
+        self.cursor = None;
+    }
+    /// Opens or closes the node `id` on the next frame.
+    pub fn set_open(&mut self, id: Id, open: bool) {
+        self.pending_open.retain(|&(ref pending, _)| *pending != id);
+        self.pending_open.push((id, open));
+    }
+    /// Builds the tree in the current window. Returns `true` if the selection changed.
+    pub fn build<S: TreeSource<Id = Id>>(&mut self, ui: &Ui, source: &mut S) -> bool {
+        let mut visible = Vec::new();
+        let mut click = None;
+        for root in source.roots() {
+            self.build_node(ui, source, root, None, &mut visible, &mut click);
+        }
+        self.pending_open.clear();
+
+        let old_selection = self.selection.clone();
+        if let Some(click) = click {
+            self.apply_click(click, &visible);
+        } else if ui.is_window_focused() && !ui.imgui().want_text_input() {
+            self.apply_keys(ui, &visible);
+        }
+        self.selection != old_selection
+    }
+    fn build_node<S: TreeSource<Id = Id>>(
+        &mut self,
+        ui: &Ui,
+        source: &mut S,
+        id: Id,
+        parent: Option<usize>,
+        visible: &mut Vec<VisibleNode<Id>>,
+        click: &mut Option<Click<Id>>,
+    ) {
+        let label = source.label(&id);
+        let has_children = source.has_children(&id);
+        let mut hasher = DefaultHasher::new();
+        id.hash(&mut hasher);
+        ui.push_id(hasher.finish() as i32);
+        let node_id = unsafe { ImStr::from_utf8_with_nul_unchecked(b"##node\0") };
+        let mut node = ui
+            .tree_node(node_id)
+            .label(&label)
+            .selected(self.is_selected(&id))
+            .open_on_arrow(true)
+            .open_on_double_click(true)
+            .leaf(!has_children);
+        if let Some(&(_, open)) = self.pending_open.iter().find(|&&(ref p, _)| *p == id) {
+            node = node.opened(open, ImGuiCond::Always);
+        }
+        let token = node.begin();
+        let result = token.result();
+        if self.scroll_to.as_ref() == Some(&id) {
+            ui.scroll_item_into_view(0.5);
+            self.scroll_to = None;
+        }
+        if result.clicked && !result.toggled {
+            let imgui = ui.imgui();
+            *click = Some(Click {
+                id: id.clone(),
+                ctrl: imgui.key_ctrl(),
+                shift: imgui.key_shift(),
+            });
+        }
+        let index = visible.len();
+        let open = has_children && result.open;
+        visible.push(VisibleNode {
+            id: id.clone(),
+            parent,
+            has_children,
+            open,
+        });
+        if open {
+            for child in source.children(&id) {
+                self.build_node(ui, source, child, Some(index), visible, click);
+            }
+        }
+        drop(token);
+        ui.pop_id();
+    }
+    fn apply_click(&mut self, click: Click<Id>, visible: &[VisibleNode<Id>]) {
+        if self.multi_select && click.shift {
+            if let Some(range) = self.range(&click.id, visible) {
+                self.selection = range;
+                self.cursor = Some(click.id);
+                return;
+            }
+        }
+        if self.multi_select && click.ctrl {
+            if self.is_selected(&click.id) {
+                self.selection.retain(|id| *id != click.id);
+            } else {
+                self.selection.push(click.id.clone());
+            }
+        } else {
+            self.selection = vec![click.id.clone()];
+        }
+        self.anchor = Some(click.id.clone());
+        self.cursor = Some(click.id);
+    }
+    fn apply_keys(&mut self, ui: &Ui, visible: &[VisibleNode<Id>]) {
+        let current = match self
+            .cursor
+            .as_ref()
+            .and_then(|cursor| visible.iter().position(|node| node.id == *cursor))
+        {
+            Some(current) => current,
+            None => return,
+        };
+        let node = &visible[current];
+        let next = if current + 1 < visible.len() {
+            Some(current + 1)
+        } else {
+            None
+        };
+        let target = if ui.is_key_pressed(Key::UpArrow) {
+            current.checked_sub(1)
+        } else if ui.is_key_pressed(Key::DownArrow) {
+            next
+        } else if ui.is_key_pressed(Key::RightArrow) {
+            if node.has_children && !node.open {
+                self.set_open(node.id.clone(), true);
+                None
+            } else if node.open {
+                next
+            } else {
+                None
+            }
+        } else if ui.is_key_pressed(Key::LeftArrow) {
+            if node.open {
+                self.set_open(node.id.clone(), false);
+                None
+            } else {
+                node.parent
+            }
+        } else {
+            None
+        };
+        if let Some(target) = target {
+            let id = visible[target].id.clone();
+            let range = if self.multi_select && ui.imgui().key_shift() {
+                self.range(&id, visible)
+            } else {
+                None
+            };
+            match range {
+                Some(range) => {
+                    self.selection = range;
+                    self.cursor = Some(id.clone());
+                }
+                None => {
+                    self.selection = vec![id.clone()];
+                    self.anchor = Some(id.clone());
+                    self.cursor = Some(id.clone());
+                }
+            }
+            self.scroll_to = Some(id);
+        }
+    }
+    // The visible nodes from the anchor to `id`, or None if the anchor is not visible
+    fn range(&self, id: &Id, visible: &[VisibleNode<Id>]) -> Option<Vec<Id>> {
+        let position = |id: &Id| visible.iter().position(|node| node.id == *id);
+        match (self.anchor.as_ref().and_then(&position), position(id)) {
+            (Some(from), Some(to)) => {
+                let (min, max) = if from <= to { (from, to) } else { (to, from) };
+                Some(
+                    visible[min..max + 1]
+                        .iter()
+                        .map(|node| node.id.clone())
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<Id: Clone + Eq + Hash> Default for TreeView<Id> {
+    fn default() -> Self { TreeView::new() }
+}
@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
+use std::os::raw::c_int;
 use sys;
 
-use super::{ImGuiCond, ImGuiTreeNodeFlags, ImStr, Ui};
+use super::{ImGuiCond, ImGuiHoveredFlags, ImGuiTreeNodeFlags, ImStr, Ui};
 
 #[must_use]
 pub struct TreeNode<'ui, 'p> {
@@ -95,12 +96,23 @@ impl<'ui, 'p> TreeNode<'ui, 'p> {
         self.flags.set(ImGuiTreeNodeFlags::FramePadding, value);
         self
     }
-    pub fn build<F: FnOnce()>(self, f: F) {
-        let render = unsafe {
+    /// Begins the tree node. If it is open and `NoTreePushOnOpen` is not set, the tree is
+    /// popped when the returned token is dropped.
+    pub fn begin(self) -> TreeNodeToken<'ui> {
+        let was_open = unsafe {
+            if self.flags.0 & ImGuiTreeNodeFlags::Leaf.0 != 0 {
+                true
+            } else {
+                let storage = sys::GetStateStorage();
+                let id = sys::GetID(self.id.as_ptr());
+                let default_open = self.flags.0 & ImGuiTreeNodeFlags::DefaultOpen.0 != 0;
+                sys::ImGuiStorage_GetInt(storage, id, default_open as c_int) != 0
+            }
+        };
+        let open = unsafe {
             if self.opened_cond != ImGuiCond::None {
                 sys::SetNextTreeNodeOpen(self.opened, self.opened_cond);
             }
-            // TODO: Check this
             sys::TreeNodeEx1(
                 self.id.as_ptr(),
                 self.flags as _,
@@ -108,8 +120,59 @@ impl<'ui, 'p> TreeNode<'ui, 'p> {
                 self.label.unwrap_or(self.id).as_ptr(),
             )
         };
-        if render {
+        let result = TreeNodeResult {
+            open,
+            clicked: unsafe { sys::IsItemClicked(0) },
+            toggled: open != was_open,
+            hovered: unsafe { sys::IsItemHovered(ImGuiHoveredFlags::None) },
+        };
+        TreeNodeToken {
+            result,
+            pop: open && self.flags.0 & ImGuiTreeNodeFlags::NoTreePushOnOpen.0 == 0,
+            _phantom: PhantomData,
+        }
+    }
+    /// Builds the children of the node with `f` if it is open.
+    pub fn build<F: FnOnce()>(self, f: F) -> TreeNodeResult {
+        let token = self.begin();
+        if token.is_open() {
             f();
+        }
+        token.result()
+    }
+}
+
+/// The state of a tree node after it was drawn.
+///
+/// A click on the arrow of a node opened on arrow or on double click both clicks and toggles
+/// it, so selection is usually handled with `clicked && !toggled`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct TreeNodeResult {
+    /// `true` if the node is open and its children should be drawn.
+    pub open: bool,
+    /// `true` if the node was clicked with the left mouse button this frame.
+    pub clicked: bool,
+    /// `true` if the node was opened or closed this frame.
+    pub toggled: bool,
+    pub hovered: bool,
+}
+
+/// Ends the tree node begun with `TreeNode::begin` when dropped.
+#[must_use]
+pub struct TreeNodeToken<'ui> {
+    result: TreeNodeResult,
+    pop: bool,
+    _phantom: PhantomData<&'ui Ui<'ui>>,
+}
+
+impl<'ui> TreeNodeToken<'ui> {
+    pub fn is_open(&self) -> bool { self.result.open }
+    pub fn result(&self) -> TreeNodeResult { self.result }
+}
+
+impl<'ui> Drop for TreeNodeToken<'ui> {
+    fn drop(&mut self) {
+        if self.pop {
             unsafe { sys::TreePop() };
         }
     }
@@ -1,15 +1,24 @@
 use std::marker::PhantomData;
+use std::os::raw::c_void;
 use std::ptr;
 use sys;
 
-use super::{ImGuiCond, ImGuiStyleVar, ImGuiWindowFlags, ImStr, ImVec2, Ui};
+use super::{ImGuiCond, ImGuiStyleVar, ImGuiWindowFlags, ImStr, Ui};
 
 #[must_use]
 pub struct Window<'ui, 'p> {
     pos: (f32, f32),
     pos_cond: ImGuiCond,
+    pos_pivot: (f32, f32),
     size: (f32, f32),
     size_cond: ImGuiCond,
+    size_constraints: Option<((f32, f32), (f32, f32))>,
+    size_callback: Option<Box<FnMut((f32, f32)) -> (f32, f32) + 'p>>,
+    content_size: Option<(f32, f32)>,
+    collapsed: bool,
+    collapsed_cond: ImGuiCond,
+    focused: bool,
+    bg_alpha: Option<f32>,
     name: &'p ImStr,
     opened: Option<&'p mut bool>,
     flags: ImGuiWindowFlags,
@@ -23,8 +32,16 @@ impl<'ui, 'p> Window<'ui, 'p> {
         Window {
             pos: (0.0, 0.0),
             pos_cond: ImGuiCond::None,
+            pos_pivot: (0.0, 0.0),
             size: (0.0, 0.0),
             size_cond: ImGuiCond::None,
+            size_constraints: None,
+            size_callback: None,
+            content_size: None,
+            collapsed: false,
+            collapsed_cond: ImGuiCond::None,
+            focused: false,
+            bg_alpha: None,
             name: name,
             opened: None,
             flags: ImGuiWindowFlags::None,
@@ -38,12 +55,82 @@ impl<'ui, 'p> Window<'ui, 'p> {
         self.pos_cond = cond;
         self
     }
+    /// Sets the point of the window placed at `position`, as a fraction of its size. Use
+    /// `(0.5, 0.5)` to center the window on its position.
+    #[inline]
+    pub fn position_pivot(mut self, pivot: (f32, f32)) -> Self {
+        self.pos_pivot = pivot;
+        self
+    }
     #[inline]
     pub fn size(mut self, size: (f32, f32), cond: ImGuiCond) -> Self {
         self.size = size;
         self.size_cond = cond;
         self
     }
+    /// Limits the window size to `min..max`. Use `-1.0` for a component to keep the current
+    /// size on that axis, or `std::f32::MAX` to leave it unbounded.
+    #[inline]
+    pub fn size_constraints(mut self, min: (f32, f32), max: (f32, f32)) -> Self {
+        self.size_constraints = Some((min, max));
+        self
+    }
+    /// Limits the window size to `min..max`, then passes the desired size to `callback`,
+    /// which returns the size to use.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate imgui;
+    /// # use imgui::*;
+    /// # fn main() {
+    /// # let mut imgui = ImGui::init();
+    /// # let ui = imgui.frame((0, 0), (0, 0), 0.1);
+    /// ui.window(im_str!("Preview"))
+    ///     .size_constraints_with_callback((160.0, 90.0), (1600.0, 900.0), |(w, _)| {
+    ///         (w, w * 9.0 / 16.0)
+    ///     })
+    ///     .build(|| {});
+    /// # }
+    /// ```
+    #[inline]
+    pub fn size_constraints_with_callback<F>(
+        mut self,
+        min: (f32, f32),
+        max: (f32, f32),
+        callback: F,
+    ) -> Self
+    where
+        F: FnMut((f32, f32)) -> (f32, f32) + 'p,
+    {
+        self.size_constraints = Some((min, max));
+        self.size_callback = Some(Box::new(callback));
+        self
+    }
+    /// Sets the size of the window contents, which sets the scrollable area. The title bar
+    /// and window padding are not included.
+    #[inline]
+    pub fn content_size(mut self, size: (f32, f32)) -> Self {
+        self.content_size = Some(size);
+        self
+    }
+    #[inline]
+    pub fn collapsed(mut self, collapsed: bool, cond: ImGuiCond) -> Self {
+        self.collapsed = collapsed;
+        self.collapsed_cond = cond;
+        self
+    }
+    /// Focuses the window and brings it to front when `value` is `true`.
+    #[inline]
+    pub fn focused(mut self, value: bool) -> Self {
+        self.focused = value;
+        self
+    }
+    /// Overrides the alpha of the window background color.
+    #[inline]
+    pub fn bg_alpha(mut self, alpha: f32) -> Self {
+        self.bg_alpha = Some(alpha);
+        self
+    }
     #[inline]
     pub fn opened(mut self, opened: &'p mut bool) -> Self {
         self.opened = Some(opened);
@@ -144,14 +231,52 @@ impl<'ui, 'p> Window<'ui, 'p> {
             .set(ImGuiWindowFlags::AlwaysUseWindowPadding, value);
         self
     }
-    pub fn build<F: FnOnce()>(self, f: F) {
+    pub fn build<F: FnOnce()>(mut self, f: F) {
+        // Called during Begin, while the callback is still borrowed below
+        unsafe extern "C" fn size_callback(data: *mut sys::ImGuiSizeCallbackData) {
+            let data = &mut *data;
+            let callback = &mut *(data.UserData as *mut Box<FnMut((f32, f32)) -> (f32, f32)>);
+            data.DesiredSize = callback(data.DesiredSize.into()).into();
+        }
+
         let render = unsafe {
             if self.pos_cond.0 != 0 {
-                sys::SetNextWindowPos(&self.pos.into() as _, self.pos_cond, &ImVec2::zero() as _);
+                sys::SetNextWindowPos(
+                    &self.pos.into() as _,
+                    self.pos_cond,
+                    &self.pos_pivot.into() as _,
+                );
             }
             if self.size_cond.0 != 0 {
                 sys::SetNextWindowSize(&self.size.into() as _, self.size_cond);
             }
+            if let Some((min, max)) = self.size_constraints {
+                let (callback, user_data): (sys::ImGuiSizeCallback, _) = match self.size_callback {
+                    Some(ref mut callback) => (
+                        Some(size_callback),
+                        callback as *mut Box<FnMut((f32, f32)) -> (f32, f32) + 'p> as *mut c_void,
+                    ),
+                    None => (None, ptr::null_mut()),
+                };
+                sys::SetNextWindowSizeConstraints(
+                    &min.into() as _,
+                    &max.into() as _,
+                    callback,
+                    user_data,
+                );
+            }
+            if let Some(size) = self.content_size {
+                sys::SetNextWindowContentSize(&size.into() as _);
+            }
+            if self.collapsed_cond.0 != 0 {
+                sys::SetNextWindowCollapsed(self.collapsed, self.collapsed_cond);
+            }
+            if self.focused {
+                sys::SetNextWindowFocus();
+            }
+            if let Some(alpha) = self.bg_alpha {
+                sys::SetNextWindowBgAlpha(alpha);
+            }
             if self.border {
                 sys::PushStyleVar(ImGuiStyleVar::WindowBorderSize, 1.0);
             }
@@ -4,6 +4,7 @@ use sys::{ImDrawCornerFlags, ImDrawList, ImU32};
 use super::{ImVec2, ImVec4, Ui};
 
 use std::marker::PhantomData;
+use std::slice;
 
 /// Wrap `ImU32` (a type typically used by ImGui to store packed colors)
 /// This type is used to represent the color of drawing primitives in ImGui's
@@ -256,6 +257,76 @@ impl<'ui> WindowDrawList<'ui> {
         BezierCurve::new(self, pos0, cp0, cp1, pos1, color)
     }
 
+    /// Returns a quadrilateral with the given 4 vertices `p1`, `p2`, `p3` and `p4` and
+    /// color `c`. Filled quadrilaterals must be convex.
+    pub fn add_quad<P1, P2, P3, P4, C>(&'ui self, p1: P1, p2: P2, p3: P3, p4: P4, c: C) -> Quad<'ui>
+    where
+        P1: Into<ImVec2>,
+        P2: Into<ImVec2>,
+        P3: Into<ImVec2>,
+        P4: Into<ImVec2>,
+        C: Into<ImColor>,
+    {
+        Quad::new(self, p1, p2, p3, p4, c)
+    }
+
+    /// Returns a line joining `points` with color `c`.
+    pub fn add_polyline<I, P, C>(&'ui self, points: I, c: C) -> Polyline<'ui>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<ImVec2>,
+        C: Into<ImColor>,
+    {
+        Polyline::new(self, points, c)
+    }
+
+    /// Draw a convex polygon with vertices `points`, filled with color `c`.
+    pub fn add_convex_poly_filled<I, P, C>(&self, points: I, c: C)
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<ImVec2>,
+        C: Into<ImColor>,
+    {
+        let points = points.into_iter().map(Into::into).collect::<Vec<ImVec2>>();
+        if points.len() < 3 {
+            return;
+        }
+        unsafe {
+            sys::ImDrawList_AddConvexPolyFilled(
+                self.draw_list,
+                points.as_ptr(),
+                points.len() as i32,
+                c.into().into(),
+            )
+        }
+    }
+
+    /// Returns an empty path, to build a shape from lines, arcs and curves before stroking
+    /// or filling it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use imgui::*;
+    /// // A gauge showing `value`, between 0.0 and 1.0
+    /// fn gauge(ui: &Ui, center: (f32, f32), radius: f32, value: f32) {
+    ///     use std::f32::consts::PI;
+    ///     let draw_list = ui.get_window_draw_list();
+    ///     let start = PI * 0.75;
+    ///     let end = start + PI * 1.5 * value;
+    ///     draw_list
+    ///         .path()
+    ///         .arc_to(center, radius, start, PI * 2.25, 32)
+    ///         .stroke([0.3, 0.3, 0.3], false, 6.0);
+    ///     draw_list
+    ///         .path()
+    ///         .line_to(center)
+    ///         .arc_to(center, radius, start, end, 32)
+    ///         .fill_convex([0.2, 0.7, 0.2]);
+    /// }
+    /// ```
+    pub fn path(&'ui self) -> Path<'ui> { Path::new(self) }
+
     /// Push a clipping rectangle on the stack, run `f` and pop it.
     ///
     /// Clip all drawings done within the closure `f` in the given
@@ -397,6 +468,12 @@ impl<'ui> Rect<'ui> {
         self
     }
 
+    /// Set which corners are rounded, replacing the `round_*` settings.
+    pub fn rounding_corners(mut self, flags: ImDrawCornerFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
     /// Set rectangle's thickness (default to 1.0 pixel).
     pub fn thickness(mut self, thickness: f32) -> Self {
         self.thickness = thickness;
@@ -654,3 +731,274 @@ impl<'ui> BezierCurve<'ui> {
         }
     }
 }
+
+/// Represents a quadrilateral about to be drawn on the window
+#[must_use = "should call .build() to draw the object"]
+pub struct Quad<'ui> {
+    p1: ImVec2,
+    p2: ImVec2,
+    p3: ImVec2,
+    p4: ImVec2,
+    color: ImColor,
+    thickness: f32,
+    filled: bool,
+    draw_list: &'ui WindowDrawList<'ui>,
+}
+
+impl<'ui> Quad<'ui> {
+    fn new<P1, P2, P3, P4, C>(
+        draw_list: &'ui WindowDrawList,
+        p1: P1,
+        p2: P2,
+        p3: P3,
+        p4: P4,
+        c: C,
+    ) -> Self
+    where
+        P1: Into<ImVec2>,
+        P2: Into<ImVec2>,
+        P3: Into<ImVec2>,
+        P4: Into<ImVec2>,
+        C: Into<ImColor>,
+    {
+        Self {
+            p1: p1.into(),
+            p2: p2.into(),
+            p3: p3.into(),
+            p4: p4.into(),
+            color: c.into(),
+            thickness: 1.0,
+            filled: false,
+            draw_list,
+        }
+    }
+
+    /// Set quadrilateral's thickness (default to 1.0 pixel)
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set to `true` to make a filled quadrilateral (default to `false`).
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Draw the quadrilateral on the window.
+    pub fn build(self) {
+        if self.filled {
+            unsafe {
+                sys::ImDrawList_AddQuadFilled(
+                    self.draw_list.draw_list,
+                    &self.p1 as _,
+                    &self.p2 as _,
+                    &self.p3 as _,
+                    &self.p4 as _,
+                    self.color.into(),
+                )
+            }
+        } else {
+            unsafe {
+                sys::ImDrawList_AddQuad(
+                    self.draw_list.draw_list,
+                    &self.p1 as _,
+                    &self.p2 as _,
+                    &self.p3 as _,
+                    &self.p4 as _,
+                    self.color.into(),
+                    self.thickness,
+                )
+            }
+        }
+    }
+}
+
+/// Represents a polyline about to be drawn on the window
+#[must_use = "should call .build() to draw the object"]
+pub struct Polyline<'ui> {
+    points: Vec<ImVec2>,
+    color: ImColor,
+    closed: bool,
+    thickness: f32,
+    draw_list: &'ui WindowDrawList<'ui>,
+}
+
+impl<'ui> Polyline<'ui> {
+    fn new<I, P, C>(draw_list: &'ui WindowDrawList, points: I, c: C) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<ImVec2>,
+        C: Into<ImColor>,
+    {
+        Self {
+            points: points.into_iter().map(Into::into).collect(),
+            color: c.into(),
+            closed: false,
+            thickness: 1.0,
+            draw_list,
+        }
+    }
+
+    /// Set to `true` to join the last point to the first one (default to `false`).
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    /// Set polyline's thickness (default to 1.0 pixel)
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Draw the polyline on the window.
+    pub fn build(self) {
+        if self.points.len() < 2 {
+            return;
+        }
+        unsafe {
+            sys::ImDrawList_AddPolyline(
+                self.draw_list.draw_list,
+                self.points.as_ptr(),
+                self.points.len() as i32,
+                self.color.into(),
+                self.closed,
+                self.thickness,
+            )
+        }
+    }
+}
+
+/// Represents a path being built, drawn with `stroke` or `fill_convex`.
+#[must_use = "should call .stroke() or .fill_convex() to draw the path"]
+pub struct Path<'ui> {
+    points: Vec<ImVec2>,
+    draw_list: &'ui WindowDrawList<'ui>,
+}
+
+impl<'ui> Path<'ui> {
+    fn new(draw_list: &'ui WindowDrawList) -> Self {
+        Self {
+            points: Vec::new(),
+            draw_list,
+        }
+    }
+
+    /// Add a point to the path.
+    pub fn line_to<P: Into<ImVec2>>(mut self, pos: P) -> Self {
+        self.points.push(pos.into());
+        self
+    }
+
+    /// Add an arc of circle from angle `a_min` to `a_max`, in radians, with
+    /// `num_segments` segments.
+    pub fn arc_to<P: Into<ImVec2>>(
+        self,
+        centre: P,
+        radius: f32,
+        a_min: f32,
+        a_max: f32,
+        num_segments: u32,
+    ) -> Self {
+        let centre = centre.into();
+        self.extend_with(|draw_list| unsafe {
+            sys::ImDrawList_PathArcTo(
+                draw_list,
+                &centre as _,
+                radius,
+                a_min,
+                a_max,
+                num_segments as i32,
+            )
+        })
+    }
+
+    /// Add an arc of circle from `a_min_of_12` to `a_max_of_12` twelfths of a turn, using
+    /// one segment per twelfth. Clockwise from the positive x axis, as screen y goes down.
+    pub fn arc_to_fast<P: Into<ImVec2>>(
+        self,
+        centre: P,
+        radius: f32,
+        a_min_of_12: i32,
+        a_max_of_12: i32,
+    ) -> Self {
+        let centre = centre.into();
+        self.extend_with(|draw_list| unsafe {
+            sys::ImDrawList_PathArcToFast(draw_list, &centre as _, radius, a_min_of_12, a_max_of_12)
+        })
+    }
+
+    /// Add a Bezier curve from the last point of the path to `p3`, whose curvature is
+    /// defined by `p1` and `p2`. With 0 segments, the curve is auto-tessellated.
+    pub fn bezier_curve_to<P1, P2, P3>(mut self, p1: P1, p2: P2, p3: P3, num_segments: u32) -> Self
+    where
+        P1: Into<ImVec2>,
+        P2: Into<ImVec2>,
+        P3: Into<ImVec2>,
+    {
+        let (p1, p2, p3) = (p1.into(), p2.into(), p3.into());
+        // imgui starts the curve from the last point of its path, so it is moved there
+        let start = self.points.pop().unwrap_or(p1);
+        self.extend_with(|draw_list| unsafe {
+            sys::ImDrawList_PathArcToFast(draw_list, &start as _, 0.0, 0, 0);
+            sys::ImDrawList_PathBezierCurveTo(
+                draw_list,
+                &p1 as _,
+                &p2 as _,
+                &p3 as _,
+                num_segments as i32,
+            )
+        })
+    }
+
+    /// Add a rectangle whose upper-left corner is at point `min` and lower-right corner is
+    /// at point `max`, with the corners in `flags` rounded by `rounding`.
+    pub fn rect<P1, P2>(self, min: P1, max: P2, rounding: f32, flags: ImDrawCornerFlags) -> Self
+    where
+        P1: Into<ImVec2>,
+        P2: Into<ImVec2>,
+    {
+        let (min, max) = (min.into(), max.into());
+        self.extend_with(|draw_list| unsafe {
+            sys::ImDrawList_PathRect(draw_list, &min as _, &max as _, rounding, flags.0 as _)
+        })
+    }
+
+    /// Draw the outline of the path with color `c`. If `closed` is `true`, the last point is
+    /// joined to the first one.
+    pub fn stroke<C: Into<ImColor>>(self, c: C, closed: bool, thickness: f32) {
+        if self.points.len() < 2 {
+            return;
+        }
+        unsafe {
+            sys::ImDrawList_AddPolyline(
+                self.draw_list.draw_list,
+                self.points.as_ptr(),
+                self.points.len() as i32,
+                c.into().into(),
+                closed,
+                thickness,
+            )
+        }
+    }
+
+    /// Fill the path with color `c`. The path must be convex.
+    pub fn fill_convex<C: Into<ImColor>>(self, c: C) {
+        self.draw_list.add_convex_poly_filled(self.points, c);
+    }
+
+    // Runs `f` on the draw list, whose own path is empty between draw calls, then moves the
+    // points it added to imgui's path into this one
+    fn extend_with<F: FnOnce(*mut ImDrawList)>(mut self, f: F) -> Self {
+        let draw_list = self.draw_list.draw_list;
+        f(draw_list);
+        unsafe {
+            let path = &mut (*draw_list)._Path;
+            self.points
+                .extend_from_slice(slice::from_raw_parts(path.Data, path.Size as usize));
+            path.Size = 0;
+        }
+        self
+    }
+}